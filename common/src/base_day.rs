@@ -1,5 +1,12 @@
-use std::fs;
+use crate::error::{AocError, AocResult, Context};
+use crate::file::{Input, InputSource};
+use crate::grid::Grid;
+use crate::params::DayParams;
+use crate::parse::{ParseError, blocks};
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
 use std::path::PathBuf;
+use std::str::FromStr;
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct DayResult {
@@ -8,42 +15,235 @@ pub struct DayResult {
     pub part_2: String,
 }
 
-pub trait BaseDay {
+/// Days are `Send` so the runner can move them onto worker threads.
+pub trait BaseDay: Send {
     fn get_day_number(&self) -> u32;
 
-    fn part_1(&mut self) -> Result<String, Box<dyn std::error::Error>>;
-    fn part_2(&mut self) -> Result<String, Box<dyn std::error::Error>>;
+    fn part_1(&mut self) -> AocResult<String>;
+    fn part_2(&mut self) -> AocResult<String>;
 
-    fn setup(&mut self) {}
+    fn setup(&mut self) -> AocResult<()> {
+        Ok(())
+    }
+
+    /// Overrides puzzle constants, e.g. the smaller grid of an example. Called
+    /// before `setup`; days without such constants ignore it.
+    fn apply_params(&mut self, _params: &DayParams) -> AocResult<()> {
+        Ok(())
+    }
 
-    fn run_day(&mut self) -> Result<DayResult, Box<dyn std::error::Error>> {
-        self.setup();
+    fn run_day(&mut self) -> AocResult<DayResult> {
+        let day = self.get_day_number();
+        self.setup().with_context(|| format!("Day {} setup", day))?;
         Ok(DayResult {
-            day: self.get_day_number(),
-            part_1: self.part_1()?,
-            part_2: self.part_2()?,
+            day,
+            part_1: self
+                .part_1()
+                .with_context(|| format!("Day {} part 1", day))?,
+            part_2: self
+                .part_2()
+                .with_context(|| format!("Day {} part 2", day))?,
+        })
+    }
+
+    fn input(&self) -> &Input;
+
+    fn input_mut(&mut self) -> &mut Input;
+
+    /// Points the day at another input, e.g. one of the examples under `data/`.
+    fn set_input(&mut self, source: InputSource) {
+        *self.input_mut() = source.into();
+    }
+
+    fn set_input_file_path(&mut self, path: PathBuf) {
+        self.set_input(InputSource::Path(path));
+    }
+
+    /// Builds a day reading from `source` instead of its downloaded input, e.g.
+    /// `Day5::new().with_input(InputSource::Text(example))`.
+    fn with_input(mut self, source: InputSource) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_input(source);
+        self
+    }
+
+    /// Shorthand for [`BaseDay::with_input`] with a file, e.g. `Day5::new()
+    /// .with_input_path("./data/day_5/example.txt")`.
+    fn with_input_path(self, path: impl Into<PathBuf>) -> Self
+    where
+        Self: Sized,
+    {
+        self.with_input(InputSource::Path(path.into()))
+    }
+
+    /// The whole input, read on first use and kept by the day.
+    fn input_str(&self) -> AocResult<&str> {
+        let input = self.input();
+        input.text().with_context(|| {
+            format!(
+                "Day {}: reading input {}",
+                self.get_day_number(),
+                input.source()
+            )
         })
     }
 
-    fn get_input_file_path(&self) -> PathBuf;
+    fn input_lines(&self) -> AocResult<Vec<&str>> {
+        Ok(self.input_str()?.lines().collect())
+    }
+
+    /// Input split on blank lines, e.g. rules and updates in separate sections.
+    fn input_blocks(&self) -> AocResult<Vec<&str>> {
+        Ok(blocks(self.input_str()?))
+    }
+
+    /// Parses each non-empty line with `FromStr`.
+    fn input_parsed<T>(&self) -> AocResult<Vec<T>>
+    where
+        Self: Sized,
+        T: FromStr,
+        T::Err: Display,
+    {
+        let mut result = Vec::new();
+        for (index, line) in self.input_str()?.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let value = line
+                .parse()
+                .map_err(|e| ParseError::new(index + 1, 1, format!("{}", e)))?;
+            result.push(value);
+        }
+
+        Ok(result)
+    }
+
+    /// Builds a grid by parsing every character with `FromStr`, so `char` keeps the
+    /// symbols as-is while numeric types read digit maps.
+    fn input_grid<T>(&self) -> AocResult<Grid<T>>
+    where
+        Self: Sized,
+        T: FromStr + PartialEq + Debug + Eq + Hash + Clone + Display,
+        T::Err: Display,
+    {
+        let mut rows = Vec::new();
+        for (row, line) in self.input_str()?.lines().enumerate() {
+            let mut values = Vec::with_capacity(line.len());
+            for (col, c) in line.chars().enumerate() {
+                let value = c.encode_utf8(&mut [0; 4]).parse().map_err(|e| {
+                    ParseError::new(row + 1, col + 1, format!("invalid cell '{}': {}", c, e))
+                })?;
+                values.push(value);
+            }
+            rows.push(values);
+        }
+
+        if rows.is_empty() {
+            return Err(
+                AocError::parse("input is empty").context(format!("Day {}", self.get_day_number()))
+            );
+        }
+
+        Ok(Grid::from_vector(&rows))
+    }
 
-    fn read_file(&self) -> String {
-        fs::read_to_string(self.get_input_file_path()).expect("Error reading input!")
+    fn read_file(&self) -> AocResult<String> {
+        Ok(self.input_str()?.to_string())
     }
 
-    fn read_file_into_vec(&self) -> Vec<String> {
-        fs::read_to_string(self.get_input_file_path())
-            .expect("Error reading input!")
-            .lines()
-            .map(String::from)
-            .collect()
+    fn read_file_into_vec(&self) -> AocResult<Vec<String>> {
+        Ok(self.input_lines()?.into_iter().map(String::from).collect())
     }
 
-    fn read_file_into_vec_of_vec(&self) -> Vec<Vec<char>> {
-        fs::read_to_string(self.get_input_file_path())
-            .expect("Error reading input!")
-            .lines()
-            .map(|c| c.chars().collect())
-            .collect()
+    fn read_file_into_vec_of_vec(&self) -> AocResult<Vec<Vec<char>>> {
+        Ok(self
+            .input_lines()?
+            .into_iter()
+            .map(|line| line.chars().collect())
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::get_data_dir;
+
+    struct ExampleDay {
+        input: Input,
+    }
+
+    impl BaseDay for ExampleDay {
+        fn get_day_number(&self) -> u32 {
+            2
+        }
+
+        fn part_1(&mut self) -> AocResult<String> {
+            Ok(self.input_lines()?.len().to_string())
+        }
+
+        fn part_2(&mut self) -> AocResult<String> {
+            Ok(String::new())
+        }
+
+        fn input(&self) -> &Input {
+            &self.input
+        }
+
+        fn input_mut(&mut self) -> &mut Input {
+            &mut self.input
+        }
+    }
+
+    #[test]
+    fn input_loaders_test() -> Result<(), Box<dyn std::error::Error>> {
+        let day = ExampleDay {
+            input: get_data_dir(2023, 5).join("example_1.txt").into(),
+        };
+
+        assert!(std::ptr::eq(day.input_str()?, day.input_str()?));
+        assert_eq!(day.input_blocks()?.len(), 8);
+        assert_eq!(day.input_lines()?[0], "seeds: 79 14 55 13");
+
+        let grid = day.input_grid::<char>()?;
+        assert_eq!(grid.get(crate::grid::Point { x: 0, y: 0 }), Some(&'s'));
+
+        Ok(())
+    }
+
+    #[test]
+    fn input_parsed_reports_line_test() {
+        let day = ExampleDay {
+            input: get_data_dir(2023, 5).join("example_1.txt").into(),
+        };
+
+        let error = day.input_parsed::<u32>().unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: Some(1), .. }));
+    }
+
+    #[test]
+    fn text_input_test() -> AocResult<()> {
+        let mut day = ExampleDay {
+            input: PathBuf::new().into(),
+        }
+        .with_input(InputSource::Text(String::from("a\nb\nc")));
+
+        assert_eq!(day.run_day()?.part_1, "3");
+
+        Ok(())
+    }
+
+    #[test]
+    fn missing_input_test() {
+        let mut day = ExampleDay {
+            input: get_data_dir(2023, 5).join("missing.txt").into(),
+        };
+
+        let error = day.run_day().unwrap_err();
+        assert!(error.to_string().starts_with("Day 2 part 1: "));
+        assert!(error.to_string().contains("missing.txt"));
+        assert!(matches!(error.root(), AocError::Io(_)));
     }
 }
@@ -1,20 +1,140 @@
+use crate::error::{AocError, AocResult};
 use crate::file::project_root;
 use figment::{
     Figment,
     providers::{Env, Format, Toml},
 };
 use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// The account configured by the top-level `cookie`.
+pub const DEFAULT_PROFILE: &str = "default";
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Profile {
+    pub cookie: String,
+}
+
+/// Where downloaded puzzle inputs are kept, see [`crate::file::InputStore`].
+#[derive(Deserialize, Debug, Clone, Copy, Default, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Storage {
+    /// Plain text under `data/`, next to the examples.
+    #[default]
+    Repo,
+    /// Plain text in the user's cache directory, outside the repository.
+    Cache,
+    /// Encrypted with `key`, in the cache directory unless `dir` says otherwise.
+    Encrypted,
+}
+
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct InputsConfig {
+    #[serde(default)]
+    pub storage: Storage,
+    /// Overrides the directory of the cache and encrypted stores.
+    pub dir: Option<PathBuf>,
+    /// Hex encoded 32 byte key of the encrypted store.
+    pub key: Option<String>,
+}
 
 #[derive(Deserialize, Debug)]
 pub struct Config {
-    pub cookie: String,
+    pub cookie: Option<String>,
+    /// Other accounts, e.g. `[profiles.alice]`, each with its own inputs.
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+    /// Where the runner keeps its history of answers and timings.
+    #[serde(default = "default_runs_db")]
+    pub runs_db: PathBuf,
+    /// Private leaderboard shown by the `leaderboard` command.
+    pub leaderboard: Option<u64>,
+    /// `[inputs]`: how puzzle inputs are stored.
+    #[serde(default)]
+    pub inputs: InputsConfig,
+}
+
+impl Config {
+    pub fn cookie_for(&self, profile: &str) -> AocResult<&str> {
+        let cookie = if profile == DEFAULT_PROFILE {
+            self.cookie.as_deref()
+        } else {
+            self.profiles.get(profile).map(|p| p.cookie.as_str())
+        };
+
+        cookie.ok_or_else(|| AocError::Config(format!("no cookie for profile '{}'", profile)))
+    }
+
+    /// The default profile followed by the named ones.
+    pub fn profile_names(&self) -> Vec<&str> {
+        let mut names = vec![DEFAULT_PROFILE];
+        names.extend(
+            self.profiles
+                .keys()
+                .map(String::as_str)
+                .filter(|name| *name != DEFAULT_PROFILE),
+        );
+        names
+    }
 }
 
-pub fn get_config() -> Result<Config, Box<figment::Error>> {
-    let config: Config = Figment::new()
-        .merge(Toml::file(project_root().join("config.toml")))
+pub fn default_runs_db() -> PathBuf {
+    project_root().join("runs.sqlite")
+}
+
+fn load(figment: Figment) -> AocResult<Config> {
+    let config: Config = figment
         .merge(Env::prefixed("AOC_"))
-        .extract()?;
+        .extract()
+        .map_err(Box::new)?;
 
     Ok(config)
 }
+
+pub fn get_config() -> AocResult<Config> {
+    load(Figment::new().merge(Toml::file(project_root().join("config.toml"))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profiles_test() -> AocResult<()> {
+        let config = load(Figment::from(Toml::string(
+            r#"
+            cookie = "session=mine"
+
+            [profiles.alice]
+            cookie = "session=alice"
+            "#,
+        )))?;
+
+        assert_eq!(config.cookie_for(DEFAULT_PROFILE)?, "session=mine");
+        assert_eq!(config.cookie_for("alice")?, "session=alice");
+        assert!(matches!(config.cookie_for("bob"), Err(AocError::Config(_))));
+        assert_eq!(config.profile_names(), vec![DEFAULT_PROFILE, "alice"]);
+        assert_eq!(config.inputs.storage, Storage::Repo);
+
+        Ok(())
+    }
+
+    #[test]
+    fn inputs_test() -> AocResult<()> {
+        let config = load(Figment::from(Toml::string(
+            r#"
+            [inputs]
+            storage = "encrypted"
+            dir = "/tmp/inputs"
+            key = "00"
+            "#,
+        )))?;
+
+        assert_eq!(config.inputs.storage, Storage::Encrypted);
+        assert_eq!(config.inputs.dir, Some(PathBuf::from("/tmp/inputs")));
+        assert_eq!(config.inputs.key.as_deref(), Some("00"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,185 @@
+use crate::parse::ParseError;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::num::ParseIntError;
+
+pub type AocResult<T> = Result<T, AocError>;
+
+#[derive(Debug)]
+pub enum AocError {
+    Io(io::Error),
+    Parse {
+        line: Option<usize>,
+        msg: String,
+    },
+    NoSolution,
+    /// A part that has not been written yet.
+    Unimplemented,
+    Download(String),
+    Config(String),
+    Other(Box<dyn Error + Send + Sync>),
+    Context {
+        context: String,
+        source: Box<AocError>,
+    },
+}
+
+impl AocError {
+    pub fn parse(msg: impl Into<String>) -> Self {
+        AocError::Parse {
+            line: None,
+            msg: msg.into(),
+        }
+    }
+
+    pub fn other(error: impl Into<Box<dyn Error + Send + Sync>>) -> Self {
+        AocError::Other(error.into())
+    }
+
+    pub fn context(self, context: impl Display) -> Self {
+        AocError::Context {
+            context: context.to_string(),
+            source: Box::new(self),
+        }
+    }
+
+    /// The innermost error, skipping any context layers.
+    pub fn root(&self) -> &AocError {
+        match self {
+            AocError::Context { source, .. } => source.root(),
+            other => other,
+        }
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "I/O error: {}", e),
+            AocError::Parse {
+                line: Some(line),
+                msg,
+            } => write!(f, "parse error on line {}: {}", line, msg),
+            AocError::Parse { line: None, msg } => write!(f, "parse error: {}", msg),
+            AocError::NoSolution => write!(f, "no solution found"),
+            AocError::Unimplemented => write!(f, "not implemented"),
+            AocError::Download(msg) => write!(f, "download failed: {}", msg),
+            AocError::Config(msg) => write!(f, "invalid configuration: {}", msg),
+            AocError::Other(e) => write!(f, "{}", e),
+            AocError::Context { context, source } => write!(f, "{}: {}", context, source),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            AocError::Other(e) => Some(e.as_ref()),
+            AocError::Context { source, .. } => Some(source.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(value: io::Error) -> Self {
+        AocError::Io(value)
+    }
+}
+
+impl From<ParseError> for AocError {
+    fn from(value: ParseError) -> Self {
+        AocError::Parse {
+            line: Some(value.line),
+            msg: format!("column {}: {}", value.column, value.message),
+        }
+    }
+}
+
+impl From<ParseIntError> for AocError {
+    fn from(value: ParseIntError) -> Self {
+        AocError::parse(value.to_string())
+    }
+}
+
+impl From<reqwest::Error> for AocError {
+    fn from(value: reqwest::Error) -> Self {
+        AocError::Download(value.to_string())
+    }
+}
+
+impl From<rusqlite::Error> for AocError {
+    fn from(value: rusqlite::Error) -> Self {
+        AocError::other(value)
+    }
+}
+
+impl From<Box<figment::Error>> for AocError {
+    fn from(value: Box<figment::Error>) -> Self {
+        AocError::Config(value.to_string())
+    }
+}
+
+/// Adds a description of what was being attempted to an error, e.g.
+/// `fs::read_to_string(&path).context("reading input")?`.
+pub trait Context<T> {
+    fn context(self, context: impl Display) -> AocResult<T>;
+
+    fn with_context<C: Display, F: FnOnce() -> C>(self, f: F) -> AocResult<T>;
+}
+
+impl<T, E: Into<AocError>> Context<T> for Result<T, E> {
+    fn context(self, context: impl Display) -> AocResult<T> {
+        self.map_err(|e| e.into().context(context))
+    }
+
+    fn with_context<C: Display, F: FnOnce() -> C>(self, f: F) -> AocResult<T> {
+        self.map_err(|e| e.into().context(f()))
+    }
+}
+
+/// A missing value becomes [`AocError::NoSolution`].
+impl<T> Context<T> for Option<T> {
+    fn context(self, context: impl Display) -> AocResult<T> {
+        self.ok_or_else(|| AocError::NoSolution.context(context))
+    }
+
+    fn with_context<C: Display, F: FnOnce() -> C>(self, f: F) -> AocResult<T> {
+        self.ok_or_else(|| AocError::NoSolution.context(f()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn context_chain_test() {
+        let result: AocResult<u32> = "x".parse::<u32>().context("reading seeds");
+        let error = result.context("Day 5 part 1").unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Day 5 part 1: reading seeds: parse error: invalid digit found in string"
+        );
+        assert!(matches!(error.root(), AocError::Parse { line: None, .. }));
+        assert!(error.source().is_some());
+    }
+
+    #[test]
+    fn option_context_test() {
+        let error = None::<u32>.context("no path to the exit").unwrap_err();
+        assert!(matches!(error.root(), AocError::NoSolution));
+    }
+
+    #[test]
+    fn parse_error_keeps_position_test() {
+        let error: AocError = ParseError::new(3, 7, "expected ','").into();
+        assert_eq!(
+            error.to_string(),
+            "parse error on line 3: column 7: expected ','"
+        );
+    }
+}
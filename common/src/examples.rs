@@ -0,0 +1,181 @@
+use crate::base_day::BaseDay;
+use crate::error::{AocError, AocResult};
+use crate::params::DayParams;
+use figment::Figment;
+use figment::providers::{Format, Toml};
+use serde::Deserialize;
+use std::fmt::{Display, Formatter};
+use std::path::{Path, PathBuf};
+
+const EXPECTED_SUFFIX: &str = ".expected.toml";
+
+/// An answer in an expectation file, written either as a TOML integer or string.
+#[derive(Deserialize, Debug, Clone, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Contents of an `example_N.expected.toml`: the answers the example should
+/// produce, each part being optional, and the [`DayParams`] it needs.
+///
+/// `input` names another example file in the same directory, so an example can
+/// be checked again with different parameters.
+#[derive(Deserialize, Debug, Default)]
+pub struct Expected {
+    pub part_1: Option<Answer>,
+    pub part_2: Option<Answer>,
+    pub input: Option<String>,
+    #[serde(default)]
+    pub params: DayParams,
+}
+
+impl Expected {
+    pub fn load(path: &Path) -> AocResult<Expected> {
+        Figment::from(Toml::file_exact(path))
+            .extract()
+            .map_err(|e| AocError::parse(format!("{}: {}", path.display(), e)))
+    }
+}
+
+/// The example input next to an expectation file, e.g. `example_1.txt` for
+/// `example_1.expected.toml`.
+pub fn example_input_path(expected_path: &Path) -> AocResult<PathBuf> {
+    let file_name = expected_path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_suffix(EXPECTED_SUFFIX))
+        .ok_or_else(|| {
+            AocError::parse(format!(
+                "{} is not named <example>{}",
+                expected_path.display(),
+                EXPECTED_SUFFIX
+            ))
+        })?;
+
+    Ok(expected_path.with_file_name(format!("{}.txt", file_name)))
+}
+
+/// Reads the day number from a `day_<n>` directory.
+fn example_day_number(expected_path: &Path) -> AocResult<u32> {
+    expected_path
+        .parent()
+        .and_then(|dir| dir.file_name())
+        .and_then(|name| name.to_str())
+        .and_then(|name| name.strip_prefix("day_"))
+        .and_then(|day| day.parse().ok())
+        .ok_or_else(|| {
+            AocError::parse(format!(
+                "{} is not inside a day_<n> directory",
+                expected_path.display()
+            ))
+        })
+}
+
+fn check_part(part: u32, expected: &Answer, result: AocResult<String>) -> AocResult<()> {
+    let answer = result.map_err(|e| e.context(format!("part {}", part)))?;
+    if answer != expected.to_string() {
+        return Err(AocError::other(format!(
+            "part {}: expected {:?} but got {:?}",
+            part,
+            expected.to_string(),
+            answer
+        )));
+    }
+
+    Ok(())
+}
+
+/// Whether an example was compared with its expected answers.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Checked {
+    Passed,
+    /// The expectation file lists no answer yet, like the one written for a new day.
+    NoAnswers,
+}
+
+/// Runs the registered day matching `expected_path` on its example and compares
+/// every part the expectation file lists. A file without any answer is skipped
+/// rather than passed, so the caller can say so.
+pub fn check_example(days: Vec<Box<dyn BaseDay>>, expected_path: &Path) -> AocResult<Checked> {
+    let day_number = example_day_number(expected_path)?;
+    let expected = Expected::load(expected_path)?;
+    if expected.part_1.is_none() && expected.part_2.is_none() {
+        return Ok(Checked::NoAnswers);
+    }
+
+    let mut day = days
+        .into_iter()
+        .find(|day| day.get_day_number() == day_number)
+        .ok_or_else(|| AocError::other(format!("day {} is not registered", day_number)))?;
+
+    let input_path = match &expected.input {
+        Some(input) => expected_path.with_file_name(input),
+        None => example_input_path(expected_path)?,
+    };
+    day.set_input_file_path(input_path);
+    day.apply_params(&expected.params)?;
+    day.setup()?;
+
+    // Parts without an expectation are skipped, as an example often only fits one part.
+    if let Some(answer) = &expected.part_1 {
+        check_part(1, answer, day.part_1())?;
+    }
+    if let Some(answer) = &expected.part_2 {
+        check_part(2, answer, day.part_2())?;
+    }
+
+    Ok(Checked::Passed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::get_data_dir;
+
+    #[test]
+    fn expected_test() -> AocResult<()> {
+        let path = get_data_dir(2023, 1).join("example_2.expected.toml");
+        let expected = Expected::load(&path)?;
+
+        assert_eq!(expected.part_1, None);
+        assert_eq!(expected.part_2, Some(Answer::Number(281)));
+        assert!(expected.params.is_empty());
+        assert_eq!(
+            example_input_path(&path)?,
+            get_data_dir(2023, 1).join("example_2.txt")
+        );
+        assert_eq!(example_day_number(&path)?, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_answers_test() -> AocResult<()> {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}/day_1", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let path = dir.join("example_1.expected.toml");
+        std::fs::write(&path, "# part_1 = \n# part_2 = \n")?;
+
+        assert_eq!(check_example(Vec::new(), &path)?, Checked::NoAnswers);
+
+        std::fs::remove_dir_all(dir.parent().unwrap())?;
+        Ok(())
+    }
+
+    #[test]
+    fn bad_name_test() {
+        let path = get_data_dir(2023, 1).join("example_2.txt");
+        assert!(example_input_path(&path).is_err());
+    }
+}
@@ -1,7 +1,84 @@
-use crate::config::get_config;
+use crate::config::{DEFAULT_PROFILE, InputsConfig, Storage, get_config};
+use crate::error::{AocError, AocResult, Context};
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use log::warn;
+use std::fmt::{Display, Formatter};
+use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use std::{env, fs, io};
 
+const ENCRYPTED_INPUT: &str = "input.enc";
+const NONCE_LEN: usize = 12;
+
+/// Where a day reads its input from.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputSource {
+    Path(PathBuf),
+    Text(String),
+}
+
+impl InputSource {
+    /// Reads everything from `reader`, e.g. stdin, into an in-memory input.
+    pub fn from_reader<R: Read>(mut reader: R) -> io::Result<InputSource> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        Ok(InputSource::Text(text))
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+            InputSource::Text(_) => write!(f, "in-memory input"),
+        }
+    }
+}
+
+/// The input a day holds. Text given in memory is used as-is, a file is read on
+/// first use and kept until the input is replaced or the day dropped.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Input {
+    source: InputSource,
+    file: OnceLock<String>,
+}
+
+impl Input {
+    pub fn source(&self) -> &InputSource {
+        &self.source
+    }
+
+    pub fn text(&self) -> io::Result<&str> {
+        match &self.source {
+            InputSource::Path(path) => {
+                if let Some(text) = self.file.get() {
+                    return Ok(text);
+                }
+                let text = fs::read_to_string(path)?;
+                Ok(self.file.get_or_init(|| text))
+            }
+            InputSource::Text(text) => Ok(text),
+        }
+    }
+}
+
+impl From<InputSource> for Input {
+    fn from(source: InputSource) -> Input {
+        Input {
+            source,
+            file: OnceLock::new(),
+        }
+    }
+}
+
+impl From<PathBuf> for Input {
+    fn from(path: PathBuf) -> Input {
+        Input::from(InputSource::Path(path))
+    }
+}
+
 pub fn project_root() -> PathBuf {
     Path::new(env!("CARGO_MANIFEST_DIR"))
         .parent()
@@ -9,6 +86,11 @@ pub fn project_root() -> PathBuf {
         .to_path_buf()
 }
 
+/// Per-user cache directory for anything that must not live in the repository.
+pub fn cache_dir() -> Option<PathBuf> {
+    dirs::cache_dir().map(|dir| dir.join("advent_of_code"))
+}
+
 pub fn get_data_dir(year: u32, day: u32) -> PathBuf {
     project_root().join(format!("data/{}/day_{}", year, day))
 }
@@ -17,33 +99,291 @@ pub fn get_input_path(year: u32, day: u32) -> PathBuf {
     get_data_dir(year, day).join("input.txt")
 }
 
-pub async fn download_input_file(year: u32, day: u32) -> Result<(), Box<dyn std::error::Error>> {
-    let dir = get_data_dir(year, day);
-    let path = get_input_path(year, day);
+/// Inputs of the default profile stay next to the examples; every other profile
+/// gets its own tree under `data/profiles/<name>`.
+pub fn get_profile_input_path(year: u32, day: u32, profile: &str) -> PathBuf {
+    if profile == DEFAULT_PROFILE {
+        return get_input_path(year, day);
+    }
+
+    project_root().join(format!(
+        "data/profiles/{}/{}/day_{}/input.txt",
+        profile, year, day
+    ))
+}
+
+/// Where downloaded puzzle inputs are kept. Examples are not affected: they are
+/// part of the repository and always stay under `data/`.
+#[derive(Clone)]
+pub enum InputStore {
+    /// `input.txt` under `data/`, see [`get_profile_input_path`].
+    Repo,
+    /// `<dir>/<profile>/<year>/day_<n>/input.txt`, outside the repository.
+    Cache(PathBuf),
+    /// Like [`InputStore::Cache`], but each file is sealed with ChaCha20-Poly1305
+    /// and named `input.enc`, so the directory may even be committed.
+    Encrypted { dir: PathBuf, key: Key },
+}
+
+impl InputStore {
+    pub fn from_config(config: &InputsConfig) -> AocResult<InputStore> {
+        let dir =
+            || {
+                config.dir.clone().or_else(cache_dir).ok_or_else(|| {
+                    AocError::Config(String::from("no cache directory, set inputs.dir"))
+                })
+            };
+
+        Ok(match config.storage {
+            Storage::Repo => InputStore::Repo,
+            Storage::Cache => InputStore::Cache(dir()?),
+            Storage::Encrypted => {
+                let key = config
+                    .key
+                    .as_deref()
+                    .and_then(|key| hex::decode(key).ok())
+                    .filter(|key| key.len() == 32)
+                    .ok_or_else(|| {
+                        AocError::Config(String::from(
+                            "inputs.key must be 64 hex digits for encrypted storage",
+                        ))
+                    })?;
+                InputStore::Encrypted {
+                    dir: dir()?,
+                    key: *Key::from_slice(&key),
+                }
+            }
+        })
+    }
+
+    /// The store described by `config.toml`.
+    pub fn configured() -> AocResult<InputStore> {
+        InputStore::from_config(&get_config()?.inputs)
+    }
+
+    /// The file an input is stored in.
+    pub fn location(&self, year: u32, day: u32, profile: &str) -> PathBuf {
+        let relative = format!("{}/{}/day_{}", profile, year, day);
+        match self {
+            InputStore::Repo => get_profile_input_path(year, day, profile),
+            InputStore::Cache(dir) => dir.join(relative).join("input.txt"),
+            InputStore::Encrypted { dir, .. } => dir.join(relative).join(ENCRYPTED_INPUT),
+        }
+    }
 
-    if fs::exists(&path).expect("unable to read file") {
-        return Ok(());
+    pub fn contains(&self, year: u32, day: u32, profile: &str) -> bool {
+        self.location(year, day, profile).exists()
     }
+
+    pub fn save(&self, year: u32, day: u32, profile: &str, input: &str) -> AocResult<()> {
+        let path = self.location(year, day, profile);
+        let content = match self {
+            InputStore::Encrypted { key, .. } => encrypt(key, input.as_bytes())?,
+            _ => input.as_bytes().to_vec(),
+        };
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        }
+        fs::write(&path, content).with_context(|| format!("writing {}", path.display()))?;
+
+        Ok(())
+    }
+
+    /// Where a day can read the input from. Encrypted inputs are decrypted in
+    /// memory and never written out in plain text.
+    pub fn load(&self, year: u32, day: u32, profile: &str) -> AocResult<InputSource> {
+        let path = self.location(year, day, profile);
+        let InputStore::Encrypted { key, .. } = self else {
+            return Ok(InputSource::Path(path));
+        };
+
+        let content = fs::read(&path).with_context(|| format!("reading {}", path.display()))?;
+        let input = decrypt(key, &content)
+            .and_then(|plain| String::from_utf8(plain).ok())
+            .ok_or_else(|| {
+                AocError::Config(format!(
+                    "unable to decrypt {}, is inputs.key right?",
+                    path.display()
+                ))
+            })?;
+
+        Ok(InputSource::Text(input))
+    }
+}
+
+/// A random nonce followed by the sealed input.
+fn encrypt(key: &Key, plain: &[u8]) -> AocResult<Vec<u8>> {
+    let nonce = ChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let sealed = ChaCha20Poly1305::new(key)
+        .encrypt(&nonce, plain)
+        .map_err(|_| AocError::other("unable to encrypt input"))?;
+
+    Ok([nonce.as_slice(), &sealed].concat())
+}
+
+fn decrypt(key: &Key, content: &[u8]) -> Option<Vec<u8>> {
+    if content.len() < NONCE_LEN {
+        return None;
+    }
+    let (nonce, sealed) = content.split_at(NONCE_LEN);
+
+    ChaCha20Poly1305::new(key)
+        .decrypt(Nonce::from_slice(nonce), sealed)
+        .ok()
+}
+
+/// Moves a profile's plain `input.txt` files for `year` out of `data/` and into
+/// `store`, returning the days that were moved. A day the store already holds
+/// is left alone, so nothing is ever overwritten.
+pub fn migrate_inputs(store: &InputStore, year: u32, profile: &str) -> AocResult<Vec<u32>> {
+    if matches!(store, InputStore::Repo) {
+        return Ok(Vec::new());
+    }
+
+    let mut moved = Vec::new();
+    for day in 1..=25 {
+        let source = get_profile_input_path(year, day, profile);
+        if !source.exists() {
+            continue;
+        }
+        if store.contains(year, day, profile) {
+            warn!(
+                "Keeping {}, the store already has an input for {} day {} ({})",
+                source.display(),
+                year,
+                day,
+                profile
+            );
+            continue;
+        }
+
+        let input =
+            fs::read_to_string(&source).with_context(|| format!("reading {}", source.display()))?;
+        store.save(year, day, profile, &input)?;
+        fs::remove_file(&source).with_context(|| format!("removing {}", source.display()))?;
+        moved.push(day);
+    }
+
+    Ok(moved)
+}
+
+pub async fn download_input_file(year: u32, day: u32) -> AocResult<InputSource> {
+    download_profile_input_file(year, day, DEFAULT_PROFILE).await
+}
+
+/// Downloads an input into the configured [`InputStore`] unless it is already
+/// there, and returns where to read it from.
+pub async fn download_profile_input_file(
+    year: u32,
+    day: u32,
+    profile: &str,
+) -> AocResult<InputSource> {
     let config = get_config()?;
+    let store = InputStore::from_config(&config.inputs)?;
+
+    if !store.contains(year, day, profile) {
+        let cookie = config.cookie_for(profile)?;
+
+        let client = reqwest::Client::new();
+        let response = client
+            .get(format!(
+                "https://adventofcode.com/{}/day/{}/input",
+                year, day
+            ))
+            .header("Cookie", cookie)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(AocError::Download(format!(
+                "{} for {} day {} ({})",
+                response.status(),
+                year,
+                day,
+                profile
+            )));
+        }
+
+        store.save(year, day, profile, &response.text().await?)?;
+    }
+
+    store.load(year, day, profile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn in_memory_input_test() -> io::Result<()> {
+        let input = Input::from(InputSource::from_reader("1\n2\n".as_bytes())?);
 
-    let client = reqwest::Client::new();
-    let response = client
-        .get(format!(
-            "https://adventofcode.com/{}/day/{}/input",
-            year, day
-        ))
-        .header("Cookie", config.cookie.as_str())
-        .send()
-        .await?;
+        assert_eq!(input.text()?, "1\n2\n");
+        assert_eq!(input.source().to_string(), "in-memory input");
 
-    let body = response.text().await?;
+        Ok(())
+    }
+
+    fn store_dir(name: &str) -> PathBuf {
+        env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn cache_store_test() -> AocResult<()> {
+        let dir = store_dir("cache");
+        let store = InputStore::Cache(dir.clone());
+
+        assert!(!store.contains(2024, 3, "alice"));
+        store.save(2024, 3, "alice", "mul(2,4)")?;
+        let path = dir.join("alice/2024/day_3/input.txt");
+        assert_eq!(
+            store.load(2024, 3, "alice")?,
+            InputSource::Path(path.clone())
+        );
+        assert_eq!(Input::from(path).text()?, "mul(2,4)");
 
-    if !fs::exists(&dir).expect("unable to read dir") {
-        fs::create_dir_all(&dir).expect("unable to create dir");
+        fs::remove_dir_all(dir)?;
+        Ok(())
     }
 
-    let output_file = fs::File::create_new(&path);
-    io::copy(&mut body.as_bytes(), &mut output_file.unwrap()).expect("unable to write to file");
+    #[test]
+    fn encrypted_store_test() -> AocResult<()> {
+        let dir = store_dir("encrypted");
+        let store = InputStore::from_config(&InputsConfig {
+            storage: Storage::Encrypted,
+            dir: Some(dir.clone()),
+            key: Some("11".repeat(32)),
+        })?;
 
-    Ok(())
+        store.save(2024, 3, DEFAULT_PROFILE, "mul(2,4)")?;
+        let sealed = fs::read(store.location(2024, 3, DEFAULT_PROFILE))?;
+        assert!(!sealed.windows(8).any(|w| w == b"mul(2,4)"));
+        assert_eq!(
+            store.load(2024, 3, DEFAULT_PROFILE)?,
+            InputSource::Text(String::from("mul(2,4)"))
+        );
+
+        let wrong_key = InputStore::Encrypted {
+            dir: dir.clone(),
+            key: *Key::from_slice(&[0; 32]),
+        };
+        assert!(wrong_key.load(2024, 3, DEFAULT_PROFILE).is_err());
+
+        fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn bad_key_test() {
+        let config = InputsConfig {
+            storage: Storage::Encrypted,
+            dir: Some(store_dir("bad-key")),
+            key: Some(String::from("not hex")),
+        };
+        assert!(matches!(
+            InputStore::from_config(&config),
+            Err(AocError::Config(_))
+        ));
+    }
 }
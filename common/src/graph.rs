@@ -1,7 +1,9 @@
+use itertools::Itertools;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
 use std::collections::{HashMap, HashSet};
 use std::fmt::Debug;
+use std::fmt::Write;
 use std::hash::Hash;
 
 #[derive(Debug, Eq, PartialEq, Clone, Hash, PartialOrd, Ord, Default, Copy)]
@@ -142,4 +144,265 @@ impl<T: PartialEq + Eq + Copy + Hash> Graph<T> {
 
         result
     }
+
+    /// Every edge once, sorted, with whether it also goes the other way with the
+    /// same weight. Weighted edges come with their weight.
+    fn drawn_edges(&self) -> Vec<(u32, u32, Option<u64>, bool)> {
+        let mut drawn = Vec::new();
+        let weighted: HashSet<(u32, u32, u64)> = self
+            .edges
+            .values()
+            .flatten()
+            .map(|edge| (edge.source, edge.target, edge.weight))
+            .collect();
+        for &(source, target, weight) in &weighted {
+            let both_ways = weighted.contains(&(target, source, weight));
+            if !both_ways || source <= target {
+                drawn.push((source, target, Some(weight), both_ways));
+            }
+        }
+
+        let simple: HashSet<(u32, u32)> = self
+            .simple_edges
+            .iter()
+            .flat_map(|(&source, targets)| targets.iter().map(move |&target| (source, target)))
+            .collect();
+        for &(source, target) in &simple {
+            let both_ways = simple.contains(&(target, source));
+            if !both_ways || source <= target {
+                drawn.push((source, target, None, both_ways));
+            }
+        }
+
+        drawn.sort();
+        drawn
+    }
+
+    fn path_steps(path: &[u32]) -> HashSet<(u32, u32)> {
+        path.windows(2).map(|step| (step[0], step[1])).collect()
+    }
+
+    /// Whether a drawn edge is taken by the path. Only an edge drawn both ways
+    /// can be taken against the direction it is drawn in.
+    fn on_path(steps: &HashSet<(u32, u32)>, source: u32, target: u32, both_ways: bool) -> bool {
+        steps.contains(&(source, target)) || (both_ways && steps.contains(&(target, source)))
+    }
+
+    /// The graph in Graphviz DOT, with nodes labelled by their value's `Debug`.
+    pub fn to_dot(&self, path: &[u32]) -> String
+    where
+        T: Debug,
+    {
+        self.to_dot_with(|value| format!("{:?}", value), path)
+    }
+
+    /// The graph in Graphviz DOT. Edges show their weight, and the nodes and
+    /// edges of `path`, e.g. one rebuilt from [`Graph::dijkstra`], are red.
+    pub fn to_dot_with(&self, label: impl Fn(&T) -> String, path: &[u32]) -> String {
+        let steps = Self::path_steps(path);
+        let mut dot = String::from("digraph G {\n");
+
+        for (id, node) in self.nodes.iter().sorted_by_key(|(id, _)| **id) {
+            let colour = if path.contains(id) {
+                ", color=red, penwidth=2"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                dot,
+                "    {} [label=\"{}\"{}];",
+                id,
+                label(&node.value)
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\""),
+                colour
+            );
+        }
+
+        for (source, target, weight, both_ways) in self.drawn_edges() {
+            let mut attributes = Vec::new();
+            if let Some(weight) = weight {
+                attributes.push(format!("label=\"{}\"", weight));
+            }
+            if both_ways {
+                attributes.push(String::from("dir=both"));
+            }
+            if Self::on_path(&steps, source, target, both_ways) {
+                attributes.push(String::from("color=red, penwidth=2"));
+            }
+
+            if attributes.is_empty() {
+                let _ = writeln!(dot, "    {} -> {};", source, target);
+            } else {
+                let _ = writeln!(
+                    dot,
+                    "    {} -> {} [{}];",
+                    source,
+                    target,
+                    attributes.join(", ")
+                );
+            }
+        }
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    /// The graph as a Mermaid flowchart, with nodes labelled by their value's
+    /// `Debug`.
+    pub fn to_mermaid(&self, path: &[u32]) -> String
+    where
+        T: Debug,
+    {
+        self.to_mermaid_with(|value| format!("{:?}", value), path)
+    }
+
+    /// The graph as a Mermaid flowchart, drawn like [`Graph::to_dot_with`].
+    pub fn to_mermaid_with(&self, label: impl Fn(&T) -> String, path: &[u32]) -> String {
+        let steps = Self::path_steps(path);
+        let mut mermaid = String::from("flowchart LR\n");
+
+        for (id, node) in self.nodes.iter().sorted_by_key(|(id, _)| **id) {
+            let _ = writeln!(
+                mermaid,
+                "    n{}[\"{}\"]",
+                id,
+                label(&node.value).replace('"', "#quot;")
+            );
+        }
+
+        let mut highlighted_links = Vec::new();
+        for (index, (source, target, weight, both_ways)) in
+            self.drawn_edges().into_iter().enumerate()
+        {
+            let arrow = if both_ways { "<-->" } else { "-->" };
+            match weight {
+                Some(weight) => {
+                    let _ = writeln!(mermaid, "    n{} {}|{}| n{}", source, arrow, weight, target);
+                }
+                None => {
+                    let _ = writeln!(mermaid, "    n{} {} n{}", source, arrow, target);
+                }
+            }
+            if Self::on_path(&steps, source, target, both_ways) {
+                highlighted_links.push(index.to_string());
+            }
+        }
+
+        for id in path.iter().unique() {
+            let _ = writeln!(mermaid, "    style n{} stroke:red,stroke-width:3px", id);
+        }
+        if !highlighted_links.is_empty() {
+            let _ = writeln!(
+                mermaid,
+                "    linkStyle {} stroke:red,stroke-width:3px",
+                highlighted_links.join(",")
+            );
+        }
+
+        mermaid
+    }
+}
+
+/// The nodes from `start` to `end` along the shortest path found by
+/// [`Graph::dijkstra`], whose last predecessor of each node is the best one.
+pub fn shortest_path(
+    predecessors: &HashMap<u32, Vec<u32>>,
+    start: u32,
+    end: u32,
+) -> Option<Vec<u32>> {
+    let mut path = vec![end];
+    let mut current = end;
+    while current != start {
+        current = *predecessors.get(&current)?.last()?;
+        path.push(current);
+    }
+    path.reverse();
+
+    Some(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Point;
+
+    /// Three corners of a square, joined both ways like `add_edges_to_graph` does.
+    fn corners() -> (Graph<Point>, [u32; 3]) {
+        let mut graph = Graph::new();
+        let a = graph.add_node(Point { x: 0, y: 0 });
+        let b = graph.add_node(Point { x: 0, y: 4 });
+        let c = graph.add_node(Point { x: 3, y: 4 });
+        for (source, target, weight) in [(a, b, 4), (b, c, 3)] {
+            graph.add_edge(source, target, weight);
+            graph.add_edge(target, source, weight);
+        }
+        graph.add_edge(a, c, 10);
+
+        (graph, [a, b, c])
+    }
+
+    #[test]
+    fn shortest_path_test() {
+        let (graph, [a, b, c]) = corners();
+        let (distances, predecessors) = graph.dijkstra(a);
+
+        assert_eq!(distances[&c], 7);
+        assert_eq!(shortest_path(&predecessors, a, c), Some(vec![a, b, c]));
+        assert_eq!(shortest_path(&predecessors, c, a), None);
+    }
+
+    #[test]
+    fn dot_test() {
+        let (graph, [a, b, c]) = corners();
+        let dot = graph.to_dot_with(|p| format!("{},{}", p.x, p.y), &[a, b, c]);
+
+        assert_eq!(
+            dot,
+            "digraph G {\n\
+             \x20   0 [label=\"0,0\", color=red, penwidth=2];\n\
+             \x20   1 [label=\"0,4\", color=red, penwidth=2];\n\
+             \x20   2 [label=\"3,4\", color=red, penwidth=2];\n\
+             \x20   0 -> 1 [label=\"4\", dir=both, color=red, penwidth=2];\n\
+             \x20   0 -> 2 [label=\"10\"];\n\
+             \x20   1 -> 2 [label=\"3\", dir=both, color=red, penwidth=2];\n\
+             }\n"
+        );
+        assert!(
+            graph
+                .to_dot(&[])
+                .contains("    0 [label=\"Point { x: 0, y: 0 }\"];\n")
+        );
+
+        // 0 -> 2 only runs the other way, so the path does not take it.
+        assert!(
+            graph
+                .to_dot(&[c, a])
+                .contains("    0 -> 2 [label=\"10\"];\n")
+        );
+        assert!(
+            graph
+                .to_dot_with(|_| String::from("\\\""), &[])
+                .contains("    0 [label=\"\\\\\\\"\"];\n")
+        );
+    }
+
+    #[test]
+    fn mermaid_test() {
+        let (mut graph, [a, b, _]) = corners();
+        graph.add_simple_edge(b, a);
+        let mermaid = graph.to_mermaid_with(|p| format!("\"{}\"", p.x), &[a, b]);
+
+        assert!(mermaid.starts_with("flowchart LR\n    n0[\"#quot;0#quot;\"]\n"));
+        assert!(
+            mermaid.contains(
+                "    n0 <-->|4| n1\n    n0 -->|10| n2\n    n1 --> n0\n    n1 <-->|3| n2\n"
+            )
+        );
+        assert!(mermaid.ends_with(
+            "    style n0 stroke:red,stroke-width:3px\n\
+             \x20   style n1 stroke:red,stroke-width:3px\n\
+             \x20   linkStyle 0 stroke:red,stroke-width:3px\n"
+        ));
+    }
 }
@@ -1,39 +1,176 @@
+use crate::render::Rgb;
+use itertools::Itertools;
 use log::debug;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/// The integer types points can be made of.
+pub trait Coordinate:
+    Copy
+    + Eq
+    + Ord
+    + Hash
+    + Debug
+    + Default
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn abs(self) -> Self;
+
+    fn rem_euclid(self, modulus: Self) -> Self;
+
+    /// The value as an index, if it is not negative and fits.
+    fn to_usize(self) -> Option<usize>;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn abs(self) -> Self {
+                    <$t>::abs(self)
+                }
 
+                fn rem_euclid(self, modulus: Self) -> Self {
+                    <$t>::rem_euclid(self, modulus)
+                }
+
+                fn to_usize(self) -> Option<usize> {
+                    usize::try_from(self).ok()
+                }
+            }
+        )*
+    };
+}
+
+coordinate!(i32, i64, i128);
+
+/// A position on a 2D map, `x` being the row and `y` the column.
 #[derive(Debug, Eq, PartialEq, Clone, Hash, PartialOrd, Ord, Copy, Default)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
+pub struct Point<T: Coordinate = i32> {
+    pub x: T,
+    pub y: T,
 }
 
 #[allow(dead_code)]
-impl Point {
-    pub fn add(&self, x: i32, y: i32) -> Point {
+impl<T: Coordinate> Point<T> {
+    pub fn new(x: T, y: T) -> Point<T> {
+        Point { x, y }
+    }
+
+    pub fn add(self, x: T, y: T) -> Point<T> {
         Point {
             x: self.x + x,
             y: self.y + y,
         }
     }
 
-    pub fn add_point(&self, other: Point) -> Point {
-        Point {
-            x: self.x + other.x,
-            y: self.y + other.y,
-        }
+    pub fn add_point(&self, other: Point<T>) -> Point<T> {
+        *self + other
+    }
+
+    pub fn add_tuple(&self, other: (T, T)) -> Point<T> {
+        *self + Point::from(other)
+    }
+
+    pub fn manhattan_distance(&self, other: Point<T>) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
     }
 
-    pub fn add_tuple(&self, other: (i32, i32)) -> Point {
+    /// Steps needed when diagonal moves are allowed too.
+    pub fn chebyshev_distance(&self, other: Point<T>) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /// The point moved back onto a `rows` by `cols` map whose edges join up,
+    /// so `-1` becomes the last row or column.
+    pub fn wrap(&self, rows: T, cols: T) -> Point<T> {
         Point {
-            x: self.x + other.0,
-            y: self.y + other.1,
+            x: self.x.rem_euclid(rows),
+            y: self.y.rem_euclid(cols),
         }
     }
 
-    pub fn manhattan_distance(&self, other: Point) -> i32 {
-        (self.x - other.x).abs() + (self.y - other.y).abs()
+    /// The row and column for indexing nested `Vec`s, unless the point is
+    /// off the top or left edge.
+    pub fn to_index(&self) -> Option<(usize, usize)> {
+        Some((self.x.to_usize()?, self.y.to_usize()?))
+    }
+}
+
+impl<T: Coordinate> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::add(self, other.x, other.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::add(self, -other.x, -other.y)
+    }
+}
+
+/// Scales both coordinates.
+impl<T: Coordinate> Mul<T> for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, factor: T) -> Point<T> {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+/// Multiplies coordinate by coordinate.
+impl<T: Coordinate> Mul for Point<T> {
+    type Output = Point<T>;
+
+    fn mul(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x * other.x, self.y * other.y)
+    }
+}
+
+impl<T: Coordinate> Neg for Point<T> {
+    type Output = Point<T>;
+
+    fn neg(self) -> Point<T> {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Coordinate> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Point<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Coordinate> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Point<T>) {
+        *self = *self - other;
+    }
+}
+
+impl<T: Coordinate> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Point<T> {
+        Point { x, y }
+    }
+}
+
+impl<T: Coordinate> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> (T, T) {
+        (point.x, point.y)
     }
 }
 
@@ -43,6 +180,8 @@ pub struct Grid<T> {
     rows: i32,
     cols: i32,
     cache: HashMap<T, HashSet<Point>>,
+    /// Whether the grid is a torus, with points outside it wrapping around.
+    wrapping: bool,
 }
 
 impl<T: PartialEq + Debug + Eq + Hash + Clone + Display> Default for Grid<T> {
@@ -76,6 +215,7 @@ impl<T: PartialEq + Debug + Eq + Hash + Clone + Display> Grid<T> {
             rows: input.len() as i32,
             cols: input[0].len() as i32,
             cache,
+            wrapping: false,
         }
     }
 
@@ -85,6 +225,7 @@ impl<T: PartialEq + Debug + Eq + Hash + Clone + Display> Grid<T> {
             rows: 0,
             cols: 0,
             cache: HashMap::new(),
+            wrapping: false,
         }
     }
 
@@ -101,8 +242,53 @@ impl<T: PartialEq + Debug + Eq + Hash + Clone + Display> Grid<T> {
         self.cache.entry(value).or_default().insert(point);
     }
 
+    /// The value at `point`, which wraps around the edges in wrapping mode.
     pub fn get(&self, point: Point) -> Option<&T> {
-        self.data.get(&point)
+        self.data.get(&self.locate(point)?)
+    }
+
+    /// Makes the edges of the grid join up, like a torus: `get`, `update`,
+    /// `contains` and `neighbours` then treat every point as its wrapped copy.
+    /// The grid keeps the size it has when this is turned on.
+    pub fn set_wrapping(&mut self, wrapping: bool) {
+        self.wrapping = wrapping;
+    }
+
+    pub fn with_wrapping(mut self) -> Grid<T> {
+        self.wrapping = true;
+        self
+    }
+
+    pub fn is_wrapping(&self) -> bool {
+        self.wrapping
+    }
+
+    /// Where `point` is stored: itself, or its wrapped copy in wrapping mode.
+    /// An empty grid has nowhere to wrap to.
+    pub fn locate(&self, point: Point) -> Option<Point> {
+        if !self.wrapping {
+            Some(point)
+        } else if self.rows > 0 && self.cols > 0 {
+            Some(point.wrap(self.rows, self.cols))
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        self.get(point).is_some()
+    }
+
+    /// The cells up, down, left and right of `point` that have a value. In
+    /// wrapping mode they come back wrapped, so searches stay on the grid, and
+    /// each cell is listed once even when both sides wrap onto it.
+    pub fn neighbours(&self, point: Point) -> Vec<Point> {
+        [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .filter_map(|(x, y)| self.locate(point.add(x, y)))
+            .filter(|neighbour| self.data.contains_key(neighbour))
+            .unique()
+            .collect()
     }
 
     pub fn find(&self, value: T) -> Option<(&Point, T)> {
@@ -117,7 +303,18 @@ impl<T: PartialEq + Debug + Eq + Hash + Clone + Display> Grid<T> {
         debug!("\n{}", self);
     }
 
+    /// A text rendering of the grid that can be configured before printing.
+    pub fn display(&self) -> GridDisplay<'_, T> {
+        GridDisplay {
+            grid: self,
+            missing: ' ',
+            axes: true,
+            overlays: Vec::new(),
+        }
+    }
+
     pub fn update(&mut self, point: Point, symbol: T) {
+        let point = self.locate(point).unwrap_or(point);
         self.data.insert(point, symbol);
     }
 
@@ -170,6 +367,194 @@ impl<T: PartialEq + Debug + Eq + Hash + Clone + Display> Grid<T> {
         None
     }
 
+    /// An empty grid of the given size, which inserts never shrink.
+    fn with_size<U>(rows: i32, cols: i32) -> Grid<U> {
+        Grid {
+            data: HashMap::new(),
+            rows,
+            cols,
+            cache: HashMap::new(),
+            wrapping: false,
+        }
+    }
+
+    /// A grid of `rows` by `cols` whose cell at each point comes from the cell
+    /// of this grid at `source(point)`. It wraps if this grid does, so turning
+    /// or mirroring a torus gives a torus.
+    fn remap(&self, rows: i32, cols: i32, source: impl Fn(Point) -> Point) -> Grid<T> {
+        let mut grid = Grid::<T>::with_size(rows, cols);
+        grid.wrapping = self.wrapping;
+        for x in 0..rows {
+            for y in 0..cols {
+                let point = Point { x, y };
+                if let Some(value) = self.get(source(point)) {
+                    grid.insert(point, value.clone());
+                }
+            }
+        }
+
+        grid
+    }
+
+    /// Rows become columns, mirroring the grid along its main diagonal.
+    pub fn transpose(&self) -> Grid<T> {
+        self.remap(self.cols, self.rows, |p| Point { x: p.y, y: p.x })
+    }
+
+    /// Turns the grid a quarter clockwise.
+    pub fn rotate_90(&self) -> Grid<T> {
+        let rows = self.rows;
+        self.remap(self.cols, self.rows, |p| Point {
+            x: rows - 1 - p.y,
+            y: p.x,
+        })
+    }
+
+    pub fn rotate_180(&self) -> Grid<T> {
+        let (rows, cols) = (self.rows, self.cols);
+        self.remap(rows, cols, |p| Point {
+            x: rows - 1 - p.x,
+            y: cols - 1 - p.y,
+        })
+    }
+
+    /// Turns the grid a quarter anticlockwise.
+    pub fn rotate_270(&self) -> Grid<T> {
+        let cols = self.cols;
+        self.remap(self.cols, self.rows, |p| Point {
+            x: p.y,
+            y: cols - 1 - p.x,
+        })
+    }
+
+    /// Mirrors the grid left to right.
+    pub fn flip_horizontal(&self) -> Grid<T> {
+        let cols = self.cols;
+        self.remap(self.rows, cols, |p| Point {
+            x: p.x,
+            y: cols - 1 - p.y,
+        })
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub fn flip_vertical(&self) -> Grid<T> {
+        let rows = self.rows;
+        self.remap(rows, self.cols, |p| Point {
+            x: rows - 1 - p.x,
+            y: p.y,
+        })
+    }
+
+    /// The `rows` by `cols` part of the grid starting at `top_left`. A part of
+    /// a wrapping grid reads across its edges but does not wrap itself.
+    pub fn sub_grid(&self, top_left: Point, rows: i32, cols: i32) -> Grid<T> {
+        let mut grid = self.remap(rows.max(0), cols.max(0), |p| top_left.add_point(p));
+        grid.wrapping = false;
+        grid
+    }
+
+    /// The top left and bottom right corners of the cells that match `keep`.
+    pub fn bounding_box(&self, keep: impl Fn(&T) -> bool) -> Option<(Point, Point)> {
+        let points: Vec<&Point> = self
+            .data
+            .iter()
+            .filter(|(_, value)| keep(value))
+            .map(|(point, _)| point)
+            .collect();
+
+        let top_left = Point {
+            x: points.iter().map(|p| p.x).min()?,
+            y: points.iter().map(|p| p.y).min()?,
+        };
+        let bottom_right = Point {
+            x: points.iter().map(|p| p.x).max()?,
+            y: points.iter().map(|p| p.y).max()?,
+        };
+
+        Some((top_left, bottom_right))
+    }
+
+    /// The smallest part of the grid holding every cell that matches `keep`,
+    /// e.g. everything but the empty space around a drawing.
+    pub fn crop(&self, keep: impl Fn(&T) -> bool) -> Grid<T> {
+        match self.bounding_box(keep) {
+            Some((top_left, bottom_right)) => self.sub_grid(
+                top_left,
+                bottom_right.x - top_left.x + 1,
+                bottom_right.y - top_left.y + 1,
+            ),
+            None => Grid::<T>::with_size(0, 0),
+        }
+    }
+
+    /// The cells of a row from left to right, `None` where there is no value.
+    pub fn row(&self, row: i32) -> impl Iterator<Item = Option<&T>> + '_ {
+        (0..self.cols).map(move |y| self.get(Point { x: row, y }))
+    }
+
+    /// The cells of a column from top to bottom, `None` where there is no value.
+    pub fn col(&self, col: i32) -> impl Iterator<Item = Option<&T>> + '_ {
+        (0..self.rows).map(move |x| self.get(Point { x, y: col }))
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = Vec<Option<&T>>> + '_ {
+        (0..self.rows).map(|row| self.row(row).collect())
+    }
+
+    pub fn iter_cols(&self) -> impl Iterator<Item = Vec<Option<&T>>> + '_ {
+        (0..self.cols).map(|col| self.col(col).collect())
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl Fn(&T) -> U) -> Grid<U>
+    where
+        U: PartialEq + Debug + Eq + Hash + Clone + Display,
+    {
+        let mut grid = Grid::<T>::with_size(self.rows, self.cols);
+        for (&point, value) in &self.data {
+            grid.insert(point, f(value));
+        }
+
+        grid
+    }
+
+    /// Replaces every cell by the cells `f` returns for it, laid out left to
+    /// right. Each cell must expand to the same number of cells.
+    pub fn widen<U>(&self, f: impl Fn(&T) -> Vec<U>) -> Grid<U>
+    where
+        U: PartialEq + Debug + Eq + Hash + Clone + Display,
+    {
+        let mut grid = Grid::<T>::with_size(self.rows, 0);
+        for (&point, value) in &self.data {
+            let cells = f(value);
+            let width = cells.len() as i32;
+            for (offset, cell) in cells.into_iter().enumerate() {
+                let y = point.y * width + offset as i32;
+                grid.insert(Point { x: point.x, y }, cell);
+            }
+        }
+
+        grid
+    }
+
+    /// The cell at `point` when the grid repeats forever in every direction.
+    pub fn get_tiled(&self, point: Point) -> Option<&T> {
+        if self.rows == 0 || self.cols == 0 {
+            return None;
+        }
+
+        self.data.get(&point.wrap(self.rows, self.cols))
+    }
+
+    /// Which copy of the grid `point` falls in when it is tiled forever, with
+    /// `(0, 0)` being the grid itself.
+    pub fn tile_of(&self, point: Point) -> (i32, i32) {
+        (
+            point.x.div_euclid(self.rows.max(1)),
+            point.y.div_euclid(self.cols.max(1)),
+        )
+    }
+
     pub fn rows(&self) -> i32 {
         self.rows
     }
@@ -184,42 +569,394 @@ impl<T: PartialEq + Debug + Eq + Hash + Clone + Display> Grid<T> {
     }
 }
 
-impl<T: PartialEq + Debug + Eq + Hash + Clone + Display> Display for Grid<T> {
+/// Points drawn over a grid, replacing the cells with a marker, colouring
+/// their background, or both.
+#[derive(Debug, Clone)]
+pub struct Overlay {
+    pub points: HashSet<Point>,
+    pub marker: Option<char>,
+    pub colour: Option<Rgb>,
+}
+
+/// A configurable text rendering of a [`Grid`], built with [`Grid::display`].
+/// Cells are right-aligned to the widest one and separated by a space when
+/// wider than one character. Missing cells are drawn with a placeholder.
+pub struct GridDisplay<'a, T> {
+    grid: &'a Grid<T>,
+    missing: char,
+    axes: bool,
+    overlays: Vec<Overlay>,
+}
+
+impl<'a, T: PartialEq + Debug + Eq + Hash + Clone + Display> GridDisplay<'a, T> {
+    /// What to draw for cells the grid has no value for, `' '` by default.
+    pub fn missing(mut self, missing: char) -> Self {
+        self.missing = missing;
+        self
+    }
+
+    /// Whether to number the rows and columns, which is the default.
+    pub fn axes(mut self, axes: bool) -> Self {
+        self.axes = axes;
+        self
+    }
+
+    /// Draws `marker` instead of the cells at `points`. Later overlays win.
+    pub fn overlay(mut self, points: impl IntoIterator<Item = Point>, marker: char) -> Self {
+        self.overlays.push(Overlay {
+            points: points.into_iter().collect(),
+            marker: Some(marker),
+            colour: None,
+        });
+        self
+    }
+
+    /// Gives the cells at `points` a coloured background in the terminal.
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, colour: Rgb) -> Self {
+        self.overlays.push(Overlay {
+            points: points.into_iter().collect(),
+            marker: None,
+            colour: Some(colour),
+        });
+        self
+    }
+
+    pub fn with_overlay(mut self, overlay: Overlay) -> Self {
+        self.overlays.push(overlay);
+        self
+    }
+
+    fn cell(&self, point: Point) -> (String, Option<Rgb>) {
+        let mut text = match self.grid.get(point) {
+            Some(value) => value.to_string(),
+            None => self.missing.to_string(),
+        };
+        let mut colour = None;
+        for overlay in self.overlays.iter().filter(|o| o.points.contains(&point)) {
+            if let Some(marker) = overlay.marker {
+                text = marker.to_string();
+            }
+            if overlay.colour.is_some() {
+                colour = overlay.colour;
+            }
+        }
+
+        (text, colour)
+    }
+}
+
+fn digits(n: i32) -> usize {
+    n.max(1).to_string().len()
+}
+
+impl<'a, T: PartialEq + Debug + Eq + Hash + Clone + Display> Display for GridDisplay<'a, T> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let mut result = "   ".to_string();
-        let mut first_row = "".to_string();
-        for col in 0..self.cols {
-            first_row.push_str(format!("{}", col % 10).as_str());
+        let (rows, cols) = (self.grid.rows, self.grid.cols);
+        let cells: Vec<Vec<(String, Option<Rgb>)>> = (0..rows)
+            .map(|x| (0..cols).map(|y| self.cell(Point { x, y })).collect())
+            .collect();
+        let width = cells
+            .iter()
+            .flatten()
+            .map(|(text, _)| text.chars().count())
+            .max()
+            .unwrap_or(1);
+        let separator = if width > 1 { " " } else { "" };
+
+        let mut lines = Vec::new();
+        let label_width = digits(rows - 1).max(2);
+        if self.axes {
+            let indent = " ".repeat(label_width + 1);
+            if width >= digits(cols - 1) {
+                let header = (0..cols)
+                    .map(|col| format!("{:>width$}", col))
+                    .join(separator);
+                lines.push(format!("{}{}", indent, header));
+            } else {
+                // One line per decimal place, so every column gets its number.
+                for place in (0..digits(cols - 1) as u32).rev() {
+                    let power = 10_i32.pow(place);
+                    let header = (0..cols)
+                        .map(|col| {
+                            let digit = if col >= power || place == 0 {
+                                char::from(b'0' + (col / power % 10) as u8)
+                            } else {
+                                ' '
+                            };
+                            format!("{:>width$}", digit)
+                        })
+                        .join(separator);
+                    lines.push(format!("{}{}", indent, header));
+                }
+            }
         }
 
-        result.push_str(&first_row);
-        result.push('\n');
-        for row in 0..self.rows - 1 {
-            result.push_str(format!("{:2} ", row).as_str());
-            for col in 0..self.cols {
-                result.push_str(
-                    format!("{}", self.data.get(&Point { x: row, y: col }).unwrap()).as_str(),
-                );
+        for (row, row_cells) in cells.iter().enumerate() {
+            let drawn = row_cells
+                .iter()
+                .map(|(text, colour)| {
+                    let text = format!("{:>width$}", text);
+                    match colour {
+                        Some([r, g, b]) => format!("\x1b[48;2;{};{};{}m{}\x1b[0m", r, g, b, text),
+                        None => text,
+                    }
+                })
+                .join(separator);
+            if self.axes {
+                lines.push(format!("{:>label_width$} {}", row, drawn));
+            } else {
+                lines.push(drawn);
             }
-            result.push('\n');
-        }
-
-        result.push_str(format!("{} ", self.rows - 1).as_str());
-        for col in 0..self.cols {
-            result.push_str(
-                format!(
-                    "{}",
-                    self.data
-                        .get(&Point {
-                            x: self.rows - 1,
-                            y: col
-                        })
-                        .unwrap()
-                )
-                .as_str(),
-            );
         }
 
-        write!(f, "{}", result)
+        write!(f, "{}", lines.join("\n"))
+    }
+}
+
+impl<T: PartialEq + Debug + Eq + Hash + Clone + Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.display())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid(rows: &[&str]) -> Grid<char> {
+        let cells: Vec<Vec<char>> = rows.iter().map(|row| row.chars().collect()).collect();
+        Grid::from_vector(&cells)
+    }
+
+    fn lines(grid: &Grid<char>) -> String {
+        grid.display().axes(false).missing('?').to_string()
+    }
+
+    #[test]
+    fn rotate_test() {
+        let grid = grid(&["ab", "cd", "ef"]);
+
+        assert_eq!(lines(&grid.transpose()), "ace\nbdf");
+        assert_eq!(lines(&grid.rotate_90()), "eca\nfdb");
+        assert_eq!(lines(&grid.rotate_180()), "fe\ndc\nba");
+        assert_eq!(lines(&grid.rotate_270()), "bdf\nace");
+        assert_eq!(lines(&grid.flip_horizontal()), "ba\ndc\nfe");
+        assert_eq!(lines(&grid.flip_vertical()), "ef\ncd\nab");
+        assert_eq!(
+            lines(&grid.rotate_90().rotate_90().rotate_90().rotate_90()),
+            lines(&grid)
+        );
+        assert_eq!(grid.rotate_90().rows(), 2);
+        assert_eq!(
+            grid.rotate_90().find('e').map(|(p, _)| *p),
+            Some(Point { x: 0, y: 0 })
+        );
+
+        let torus = grid.with_wrapping();
+        assert!(torus.transpose().is_wrapping());
+        assert_eq!(torus.rotate_90().get(Point { x: -1, y: 3 }), Some(&'f'));
+        assert!(!torus.sub_grid(Point { x: 0, y: 0 }, 2, 2).is_wrapping());
+    }
+
+    #[test]
+    fn crop_test() {
+        let grid = grid(&[".....", "..#..", ".#.#.", "....."]);
+
+        assert_eq!(
+            grid.bounding_box(|c| *c == '#'),
+            Some((Point { x: 1, y: 1 }, Point { x: 2, y: 3 }))
+        );
+        assert_eq!(lines(&grid.crop(|c| *c == '#')), ".#.\n#.#");
+        assert_eq!(grid.crop(|c| *c == 'x').rows(), 0);
+        assert_eq!(
+            lines(&grid.sub_grid(Point { x: 2, y: 3 }, 2, 3)),
+            "#.?\n..?"
+        );
+    }
+
+    #[test]
+    fn rows_and_cols_test() {
+        let grid = grid(&["ab", "cd"]);
+
+        assert_eq!(grid.row(1).flatten().collect::<String>(), "cd");
+        assert_eq!(grid.col(1).flatten().collect::<String>(), "bd");
+        assert_eq!(grid.iter_rows().count(), 2);
+        assert_eq!(grid.iter_cols().next(), Some(vec![Some(&'a'), Some(&'c')]));
+    }
+
+    #[test]
+    fn map_test() {
+        let grid = grid(&["#.", ".#"]);
+        let walls = grid.map(|c| u8::from(*c == '#'));
+        assert_eq!(walls.count_values(1), 2);
+        assert_eq!(walls.get(Point { x: 1, y: 1 }), Some(&1));
+
+        let wide = grid.widen(|c| vec![*c, *c]);
+        assert_eq!(lines(&wide), "##..\n..##");
+    }
+
+    #[test]
+    fn tiled_test() {
+        let grid = grid(&["ab", "cd", "ef"]);
+
+        assert_eq!(grid.get_tiled(Point { x: 4, y: 3 }), Some(&'d'));
+        assert_eq!(grid.get_tiled(Point { x: -1, y: -1 }), Some(&'f'));
+        assert_eq!(grid.tile_of(Point { x: -1, y: 5 }), (-1, 2));
+        assert_eq!(grid.tile_of(Point { x: 2, y: 1 }), (0, 0));
+    }
+
+    #[test]
+    fn point_test() {
+        let a = Point::new(3, -2);
+        let b = Point::from((1, 4));
+
+        assert_eq!(a + b, Point::new(4, 2));
+        assert_eq!(a - b, Point::new(2, -6));
+        assert_eq!(a * 3, Point::new(9, -6));
+        assert_eq!(a * b, Point::new(3, -8));
+        assert_eq!(-a, Point::new(-3, 2));
+        assert_eq!(a.add(1, 1), Point::new(4, -1));
+        assert_eq!(a.manhattan_distance(b), 8);
+        assert_eq!(a.chebyshev_distance(b), 6);
+        assert_eq!(<(i32, i32)>::from(b), (1, 4));
+
+        let mut c = a;
+        c += b;
+        c -= Point::new(0, 2);
+        assert_eq!(c, Point::new(4, 0));
+    }
+
+    #[test]
+    fn wide_point_test() {
+        let far: Point<i64> = Point::new(7, -3) * 1_000_000_000_000;
+        assert_eq!(far.manhattan_distance(Point::default()), 10_000_000_000_000);
+        assert_eq!(far.wrap(103, 101), Point::new(56, 98));
+        assert_eq!(Point::<i64>::new(-1, 0).wrap(103, 101), Point::new(102, 0));
+    }
+
+    #[test]
+    fn index_test() {
+        assert_eq!(Point::new(2, 5).to_index(), Some((2, 5)));
+        assert_eq!(Point::new(-1, 5).to_index(), None);
+        assert_eq!(Point::<i64>::new(0, -4).to_index(), None);
+    }
+
+    #[test]
+    fn wrap_test() {
+        assert_eq!(Point { x: -1, y: 7 }.wrap(3, 5), Point { x: 2, y: 2 });
+        assert_eq!(Point { x: -7, y: -5 }.wrap(3, 5), Point { x: 2, y: 0 });
+        assert_eq!(Point { x: 1, y: 4 }.wrap(3, 5), Point { x: 1, y: 4 });
+    }
+
+    #[test]
+    fn wrapping_grid_test() {
+        let mut grid = grid(&["ab", "cd", "ef"]);
+        assert_eq!(grid.get(Point { x: -1, y: 0 }), None);
+        assert_eq!(grid.neighbours(Point { x: 0, y: 0 }).len(), 2);
+
+        grid.set_wrapping(true);
+        assert_eq!(grid.get(Point { x: -1, y: 0 }), Some(&'e'));
+        assert_eq!(grid.get(Point { x: 3, y: -1 }), Some(&'b'));
+        assert!(grid.contains(Point { x: 100, y: -100 }));
+        assert_eq!(
+            grid.neighbours(Point { x: 0, y: 0 }),
+            vec![
+                Point { x: 2, y: 0 },
+                Point { x: 1, y: 0 },
+                Point { x: 0, y: 1 }
+            ]
+        );
+
+        grid.update(Point { x: 5, y: 3 }, 'z');
+        assert_eq!(grid.get(Point { x: 2, y: 1 }), Some(&'z'));
+        assert_eq!(grid.rows(), 3);
+    }
+
+    #[test]
+    fn wrapping_search_test() {
+        let mut grid = grid(&["S.#.", "###."]);
+        let distances =
+            crate::utils::grid_bfs_distances(&grid, Point { x: 0, y: 0 }, |c| *c != '#');
+        assert_eq!(distances.get(&Point { x: 0, y: 3 }), None);
+
+        grid.set_wrapping(true);
+        let distances =
+            crate::utils::grid_bfs_distances(&grid, Point { x: 0, y: 0 }, |c| *c != '#');
+        assert_eq!(distances.get(&Point { x: 0, y: 3 }), Some(&1));
+        assert_eq!(distances.get(&Point { x: 1, y: 3 }), Some(&2));
+    }
+
+    #[test]
+    fn display_test() {
+        let grid = grid(&["#..", ".#."]);
+        assert_eq!(grid.to_string(), "   012\n 0 #..\n 1 .#.");
+        assert_eq!(grid.display().axes(false).to_string(), "#..\n.#.");
+    }
+
+    #[test]
+    fn sparse_display_test() {
+        let mut grid = Grid::new();
+        grid.insert(Point { x: 0, y: 0 }, 7);
+        grid.insert(Point { x: 1, y: 2 }, 12);
+
+        assert_eq!(
+            grid.display().missing('.').to_string(),
+            "    0  1  2\n 0  7  .  .\n 1  .  . 12"
+        );
+    }
+
+    #[test]
+    fn wide_axes_test() {
+        let mut grid = Grid::new();
+        grid.insert(Point { x: 100, y: 11 }, 'x');
+        let text = grid.display().missing('.').to_string();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(lines[0], "              11");
+        assert_eq!(lines[1], "    012345678901");
+        assert_eq!(lines[2], "  0 ............");
+        assert_eq!(lines[102], "100 ...........x");
+    }
+
+    #[test]
+    fn wide_cell_axes_test() {
+        let mut grid = Grid::new();
+        grid.insert(Point { x: 0, y: 0 }, 12);
+        grid.insert(Point { x: 1, y: 104 }, 7);
+        let text = grid.display().missing('.').to_string();
+        let lines: Vec<&str> = text.lines().collect();
+        let column = |line: &str, col: usize| line[3 + col * 3..5 + col * 3].to_string();
+
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[3].len(), lines[2].len());
+        assert_eq!(
+            (0..3).map(|l| column(lines[l], 12)).collect::<Vec<_>>(),
+            ["  ", " 1", " 2"]
+        );
+        assert_eq!(
+            (0..3).map(|l| column(lines[l], 104)).collect::<Vec<_>>(),
+            [" 1", " 0", " 4"]
+        );
+        assert_eq!(column(lines[3], 0), "12");
+        assert_eq!(column(lines[3], 1), " .");
+        assert_eq!(column(lines[4], 104), " 7");
+    }
+
+    #[test]
+    fn overlay_test() {
+        let grid = grid(&["...", "..."]);
+        let path = [
+            Point { x: 0, y: 0 },
+            Point { x: 0, y: 1 },
+            Point { x: 1, y: 1 },
+        ];
+        let text = grid
+            .display()
+            .axes(false)
+            .overlay(path, 'O')
+            .highlight([Point { x: 1, y: 2 }], [255, 0, 0])
+            .to_string();
+
+        assert_eq!(text, "OO.\n.O\x1b[48;2;255;0;0m.\x1b[0m");
     }
 }
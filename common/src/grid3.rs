@@ -0,0 +1,427 @@
+use crate::grid::Coordinate;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::ops::{Add, Mul, Neg, Sub};
+
+#[derive(Debug, Eq, PartialEq, Clone, Hash, PartialOrd, Ord, Copy, Default)]
+pub struct Point3<T: Coordinate = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+/// One of the three directions of space.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, Hash)]
+pub enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub fn new(x: T, y: T, z: T) -> Point3<T> {
+        Point3 { x, y, z }
+    }
+
+    pub fn add(self, x: T, y: T, z: T) -> Point3<T> {
+        Point3 {
+            x: self.x + x,
+            y: self.y + y,
+            z: self.z + z,
+        }
+    }
+
+    pub fn manhattan_distance(&self, other: Point3<T>) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn get(&self, axis: Axis) -> T {
+        match axis {
+            Axis::X => self.x,
+            Axis::Y => self.y,
+            Axis::Z => self.z,
+        }
+    }
+
+    /// The same point with its coordinate along `axis` replaced.
+    pub fn with(&self, axis: Axis, value: T) -> Point3<T> {
+        let mut point = *self;
+        match axis {
+            Axis::X => point.x = value,
+            Axis::Y => point.y = value,
+            Axis::Z => point.z = value,
+        }
+        point
+    }
+
+    /// The points sharing a face with this one.
+    pub fn neighbours_6(&self) -> [Point3<T>; 6] {
+        let (one, zero) = (T::ONE, T::ZERO);
+        [
+            self.add(-one, zero, zero),
+            self.add(one, zero, zero),
+            self.add(zero, -one, zero),
+            self.add(zero, one, zero),
+            self.add(zero, zero, -one),
+            self.add(zero, zero, one),
+        ]
+    }
+
+    /// The points sharing a face, an edge or a corner with this one.
+    pub fn neighbours_26(&self) -> Vec<Point3<T>> {
+        let steps = [-T::ONE, T::ZERO, T::ONE];
+        let mut neighbours = Vec::with_capacity(26);
+        for x in steps {
+            for y in steps {
+                for z in steps {
+                    if (x, y, z) != (T::ZERO, T::ZERO, T::ZERO) {
+                        neighbours.push(self.add(x, y, z));
+                    }
+                }
+            }
+        }
+
+        neighbours
+    }
+}
+
+impl<T: Coordinate> Add for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, other: Point3<T>) -> Point3<T> {
+        Point3::add(self, other.x, other.y, other.z)
+    }
+}
+
+impl<T: Coordinate> Sub for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, other: Point3<T>) -> Point3<T> {
+        Point3::add(self, -other.x, -other.y, -other.z)
+    }
+}
+
+impl<T: Coordinate> Mul<T> for Point3<T> {
+    type Output = Point3<T>;
+
+    fn mul(self, factor: T) -> Point3<T> {
+        Point3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl<T: Coordinate> Neg for Point3<T> {
+    type Output = Point3<T>;
+
+    fn neg(self) -> Point3<T> {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Axis {
+    /// The other two axes, which span the plane across this one.
+    pub fn others(self) -> (Axis, Axis) {
+        match self {
+            Axis::X => (Axis::Y, Axis::Z),
+            Axis::Y => (Axis::X, Axis::Z),
+            Axis::Z => (Axis::X, Axis::Y),
+        }
+    }
+}
+
+/// A sparse grid of voxels.
+#[derive(Debug, Clone, Default)]
+pub struct Grid3<T> {
+    data: HashMap<Point3, T>,
+}
+
+impl<T> Grid3<T> {
+    pub fn new() -> Grid3<T> {
+        Grid3 {
+            data: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, point: Point3, value: T) -> Option<T> {
+        self.data.insert(point, value)
+    }
+
+    pub fn remove(&mut self, point: Point3) -> Option<T> {
+        self.data.remove(&point)
+    }
+
+    pub fn get(&self, point: Point3) -> Option<&T> {
+        self.data.get(&point)
+    }
+
+    pub fn contains(&self, point: Point3) -> bool {
+        self.data.contains_key(&point)
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Point3, &T)> {
+        self.data.iter()
+    }
+
+    /// The lowest and highest corners of the filled voxels.
+    pub fn bounds(&self) -> Option<(Point3, Point3)> {
+        let mut points = self.data.keys();
+        let first = *points.next()?;
+
+        Some(points.fold((first, first), |(low, high), p| {
+            (
+                Point3::new(low.x.min(p.x), low.y.min(p.y), low.z.min(p.z)),
+                Point3::new(high.x.max(p.x), high.y.max(p.y), high.z.max(p.z)),
+            )
+        }))
+    }
+
+    /// Faces of filled voxels that do not touch another filled voxel,
+    /// including those facing trapped pockets of air.
+    pub fn surface_area(&self) -> usize {
+        self.data
+            .keys()
+            .flat_map(|p| p.neighbours_6())
+            .filter(|n| !self.contains(*n))
+            .count()
+    }
+
+    /// Faces of filled voxels reachable from outside, leaving out the inside
+    /// of closed pockets.
+    pub fn exterior_surface_area(&self) -> usize {
+        let Some((low, high)) = self.bounds() else {
+            return 0;
+        };
+        let (low, high) = (low.add(-1, -1, -1), high.add(1, 1, 1));
+        let outside = self.flood_fill(low, |p, value| value.is_none() && within(p, low, high));
+
+        self.data
+            .keys()
+            .flat_map(|p| p.neighbours_6())
+            .filter(|n| outside.contains(n))
+            .count()
+    }
+
+    /// Every point reachable from `start` through face neighbours for which
+    /// `enter` holds. `enter` sees the voxel's value, if it has one, and must
+    /// bound the search since the grid itself has no edges.
+    pub fn flood_fill(
+        &self,
+        start: Point3,
+        enter: impl Fn(Point3, Option<&T>) -> bool,
+    ) -> HashSet<Point3> {
+        let mut seen = HashSet::new();
+        if !enter(start, self.get(start)) {
+            return seen;
+        }
+
+        let mut queue = VecDeque::from([start]);
+        seen.insert(start);
+        while let Some(point) = queue.pop_front() {
+            for neighbour in point.neighbours_6() {
+                if !seen.contains(&neighbour) && enter(neighbour, self.get(neighbour)) {
+                    seen.insert(neighbour);
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+
+        seen
+    }
+}
+
+fn within(point: Point3, low: Point3, high: Point3) -> bool {
+    (low.x..=high.x).contains(&point.x)
+        && (low.y..=high.y).contains(&point.y)
+        && (low.z..=high.z).contains(&point.z)
+}
+
+/// Where objects came to rest after [`drop_objects`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settled {
+    /// The cells of each object after falling, in the order they were given.
+    pub objects: Vec<Vec<Point3>>,
+    /// For each object, the objects it rests on.
+    pub supported_by: Vec<HashSet<usize>>,
+    /// How far each object fell.
+    pub fallen: Vec<i32>,
+}
+
+impl Settled {
+    /// For each object, the objects resting on it.
+    pub fn supporting(&self) -> Vec<HashSet<usize>> {
+        let mut supporting = vec![HashSet::new(); self.objects.len()];
+        for (object, below) in self.supported_by.iter().enumerate() {
+            for &support in below {
+                supporting[support].insert(object);
+            }
+        }
+
+        supporting
+    }
+}
+
+/// Lets solid objects fall along `axis`, towards lower coordinates, until they
+/// land on `floor` or on another object. Objects keep their shape, so an object
+/// whose bottom is not flat lands on whichever column it reaches first. They fall
+/// in order of their lowest cell, which assumes no object reaches underneath
+/// one that falls after it.
+pub fn drop_objects(objects: &[Vec<Point3>], axis: Axis, floor: i32) -> Settled {
+    let (a, b) = axis.others();
+    let lowest = |cells: &[Point3]| cells.iter().map(|p| p.get(axis)).min().unwrap_or(floor);
+
+    let mut order: Vec<usize> = (0..objects.len()).collect();
+    order.sort_by_key(|&index| lowest(&objects[index]));
+
+    // The highest filled level above each column, and whose it is.
+    let mut tops: HashMap<(i32, i32), (i32, usize)> = HashMap::new();
+    let mut settled = Settled {
+        objects: objects.to_vec(),
+        supported_by: vec![HashSet::new(); objects.len()],
+        fallen: vec![0; objects.len()],
+    };
+
+    for index in order {
+        let cells = &objects[index];
+        // The lowest cell of the object in each column it covers.
+        let mut bottoms: HashMap<(i32, i32), i32> = HashMap::new();
+        for p in cells {
+            let bottom = bottoms.entry((p.get(a), p.get(b))).or_insert(p.get(axis));
+            *bottom = (*bottom).min(p.get(axis));
+        }
+        // How far each column can fall before it touches the floor or an object.
+        let clearance = |(column, bottom): (&(i32, i32), &i32)| {
+            let rest = tops
+                .get(column)
+                .map_or(floor, |&(top, _)| (top + 1).max(floor));
+            bottom - rest
+        };
+        let closest = bottoms.iter().map(clearance).min().unwrap_or(0);
+
+        let fall = closest.max(0);
+        let moved: Vec<Point3> = cells
+            .iter()
+            .map(|p| p.with(axis, p.get(axis) - fall))
+            .collect();
+
+        // An object that started inside another one did not land on anything.
+        if closest >= 0 {
+            for (column, bottom) in &bottoms {
+                if let Some(&(top, below)) = tops.get(column)
+                    && top == bottom - fall - 1
+                {
+                    settled.supported_by[index].insert(below);
+                }
+            }
+        }
+        for p in &moved {
+            let column = (p.get(a), p.get(b));
+            let level = p.get(axis);
+            if tops.get(&column).is_none_or(|&(top, _)| level > top) {
+                tops.insert(column, (level, index));
+            }
+        }
+
+        settled.fallen[index] = fall;
+        settled.objects[index] = moved;
+    }
+
+    settled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A straight line of cells between two corners, like a brick.
+    fn brick(from: (i32, i32, i32), to: (i32, i32, i32)) -> Vec<Point3> {
+        let mut cells = Vec::new();
+        for x in from.0..=to.0 {
+            for y in from.1..=to.1 {
+                for z in from.2..=to.2 {
+                    cells.push(Point3::new(x, y, z));
+                }
+            }
+        }
+        cells
+    }
+
+    #[test]
+    fn point_test() {
+        let a = Point3::new(1, 2, 3);
+        let b = Point3::new(-2, 0, 5);
+
+        assert_eq!(a + b, Point3::new(-1, 2, 8));
+        assert_eq!(a - b, Point3::new(3, 2, -2));
+        assert_eq!(-a * 2, Point3::new(-2, -4, -6));
+        assert_eq!(a.manhattan_distance(b), 7);
+        assert_eq!(a.neighbours_6().len(), 6);
+        assert_eq!(a.neighbours_26().len(), 26);
+        assert!(!a.neighbours_26().contains(&a));
+
+        let far: Point3<i64> = Point3::new(19, 13, 30) * 10_000_000_000;
+        assert_eq!(far.manhattan_distance(Point3::default()), 620_000_000_000);
+    }
+
+    #[test]
+    fn surface_test() {
+        let mut grid = Grid3::new();
+        for cell in brick((0, 0, 0), (2, 2, 2)) {
+            grid.insert(cell, ());
+        }
+        assert_eq!(grid.surface_area(), 54);
+
+        grid.remove(Point3::new(1, 1, 1));
+        assert_eq!(grid.surface_area(), 60);
+        assert_eq!(grid.exterior_surface_area(), 54);
+        assert_eq!(
+            grid.bounds(),
+            Some((Point3::new(0, 0, 0), Point3::new(2, 2, 2)))
+        );
+    }
+
+    #[test]
+    fn drop_test() {
+        // The first bricks of 2023 day 22's example, in a shuffled order.
+        let bricks = vec![
+            brick((0, 0, 4), (0, 2, 4)),
+            brick((1, 0, 1), (1, 2, 1)),
+            brick((0, 0, 2), (2, 0, 2)),
+            brick((0, 2, 3), (2, 2, 3)),
+            brick((2, 0, 5), (2, 2, 5)),
+        ];
+        let settled = drop_objects(&bricks, Axis::Z, 1);
+
+        assert_eq!(settled.fallen, vec![1, 0, 0, 1, 2]);
+        assert_eq!(settled.supported_by[1], HashSet::new());
+        assert_eq!(settled.supported_by[2], HashSet::from([1]));
+        assert_eq!(settled.supported_by[3], HashSet::from([1]));
+        assert_eq!(settled.supported_by[0], HashSet::from([2, 3]));
+        assert_eq!(settled.supported_by[4], HashSet::from([2, 3]));
+        assert_eq!(settled.supporting()[3], HashSet::from([0, 4]));
+        assert!(settled.objects[4].iter().all(|p| p.z == 3));
+    }
+
+    #[test]
+    fn uneven_drop_test() {
+        // An L whose foot hangs next to a low brick and whose arm rests on a
+        // tall one, so only the arm's column stops it.
+        let l_shape = [brick((0, 0, 10), (0, 0, 12)), brick((1, 0, 12), (2, 0, 12))].concat();
+        let objects = vec![
+            brick((0, 0, 1), (0, 0, 1)),
+            brick((2, 0, 1), (2, 0, 8)),
+            l_shape,
+        ];
+        let settled = drop_objects(&objects, Axis::Z, 1);
+
+        assert_eq!(settled.fallen, vec![0, 0, 3]);
+        assert_eq!(settled.supported_by[2], HashSet::from([1]));
+        assert!(settled.objects[2].contains(&Point3::new(0, 0, 7)));
+        assert_eq!(settled.supporting()[0], HashSet::new());
+    }
+}
@@ -0,0 +1,395 @@
+use crate::config::{default_runs_db, get_config};
+use crate::error::AocResult;
+use crate::file::project_root;
+use crate::runner::DayReport;
+use rusqlite::{Connection, OptionalExtension, params};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+use sysinfo::{CpuRefreshKind, RefreshKind, System};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    started_at INTEGER NOT NULL,
+    revision TEXT NOT NULL,
+    machine TEXT NOT NULL,
+    profile TEXT NOT NULL DEFAULT 'default'
+);
+CREATE TABLE IF NOT EXISTS results (
+    run_id INTEGER NOT NULL REFERENCES runs(id),
+    year INTEGER NOT NULL,
+    day INTEGER NOT NULL,
+    part INTEGER NOT NULL,
+    status TEXT NOT NULL,
+    answer TEXT,
+    duration_ns INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS results_day ON results (year, day, part);
+";
+
+/// Where and on what a run happened, stored alongside its results.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RunInfo {
+    pub revision: String,
+    pub machine: String,
+    /// Whose input the run used.
+    pub profile: String,
+}
+
+impl RunInfo {
+    pub fn current(profile: &str) -> RunInfo {
+        RunInfo {
+            revision: git_revision().unwrap_or_else(|| String::from("unknown")),
+            machine: machine_description(),
+            profile: profile.to_string(),
+        }
+    }
+}
+
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(project_root())
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Short hash of `HEAD`, with `-dirty` appended when tracked files have changed.
+fn git_revision() -> Option<String> {
+    let revision = git(&["rev-parse", "--short", "HEAD"])?;
+    let changes = git(&["status", "--porcelain", "--untracked-files=no"])?;
+
+    Some(if changes.is_empty() {
+        revision
+    } else {
+        format!("{}-dirty", revision)
+    })
+}
+
+fn machine_description() -> String {
+    let system =
+        System::new_with_specifics(RefreshKind::nothing().with_cpu(CpuRefreshKind::nothing()));
+    let cpu = system
+        .cpus()
+        .first()
+        .map_or("unknown cpu", |cpu| cpu.brand().trim());
+
+    format!(
+        "{} ({}, {} cores, {})",
+        System::host_name().unwrap_or_else(|| String::from("unknown host")),
+        cpu,
+        system.cpus().len(),
+        System::long_os_version().unwrap_or_else(|| String::from("unknown os")),
+    )
+}
+
+/// The configured history database, or the default location when there is no
+/// usable configuration.
+pub fn runs_db_path() -> PathBuf {
+    get_config().map_or_else(|_| default_runs_db(), |config| config.runs_db)
+}
+
+/// One part of one recorded run.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct HistoryEntry {
+    pub run_id: i64,
+    pub started_at: String,
+    pub revision: String,
+    pub machine: String,
+    pub part: u32,
+    pub status: String,
+    pub answer: Option<String>,
+    pub duration: Duration,
+}
+
+/// Everything recorded about one part of a day.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PartProgress {
+    pub day: u32,
+    pub part: u32,
+    /// Whether any run solved it.
+    pub solved: bool,
+    pub latest_status: String,
+}
+
+pub struct History {
+    conn: Connection,
+}
+
+impl History {
+    pub fn open(path: &Path) -> AocResult<History> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch(SCHEMA)?;
+
+        // Databases created before profiles existed only hold default runs.
+        let has_profile: bool = conn.query_row(
+            "SELECT COUNT(*) > 0 FROM pragma_table_info('runs') WHERE name = 'profile'",
+            [],
+            |row| row.get(0),
+        )?;
+        if !has_profile {
+            conn.execute_batch(
+                "ALTER TABLE runs ADD COLUMN profile TEXT NOT NULL DEFAULT 'default'",
+            )?;
+        }
+
+        Ok(History { conn })
+    }
+
+    pub fn record(&mut self, info: &RunInfo, reports: &[DayReport]) -> AocResult<i64> {
+        let tx = self.conn.transaction()?;
+        tx.execute(
+            "INSERT INTO runs (started_at, revision, machine, profile)
+             VALUES (CAST(strftime('%s', 'now') AS INTEGER), ?, ?, ?)",
+            params![info.revision, info.machine, info.profile],
+        )?;
+        let run_id = tx.last_insert_rowid();
+
+        {
+            let mut insert = tx.prepare(
+                "INSERT INTO results (run_id, year, day, part, status, answer, duration_ns)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )?;
+            for report in reports {
+                for (part, result) in [(1, &report.part_1), (2, &report.part_2)] {
+                    insert.execute(params![
+                        run_id,
+                        report.year,
+                        report.day,
+                        part,
+                        result.status.kind(),
+                        result.status.answer(),
+                        result.duration.as_nanos() as i64,
+                    ])?;
+                }
+            }
+        }
+        tx.commit()?;
+
+        Ok(run_id)
+    }
+
+    /// The most recent solved answer of a part on a profile's input.
+    pub fn recorded_answer(
+        &self,
+        year: u32,
+        day: u32,
+        part: u32,
+        profile: &str,
+    ) -> AocResult<Option<String>> {
+        let answer = self
+            .conn
+            .query_row(
+                "SELECT s.answer
+                 FROM results s
+                 JOIN runs r ON r.id = s.run_id
+                 WHERE s.year = ? AND s.day = ? AND s.part = ? AND r.profile = ?
+                   AND s.status = 'solved'
+                 ORDER BY r.id DESC
+                 LIMIT 1",
+                params![year, day, part, profile],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        Ok(answer)
+    }
+
+    /// The progress of every part of `year` that was ever run on a profile's input.
+    pub fn progress(&self, year: u32, profile: &str) -> AocResult<Vec<PartProgress>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT s.day, s.part, MAX(s.status = 'solved'), (
+                 SELECT s2.status FROM results s2
+                 JOIN runs r2 ON r2.id = s2.run_id
+                 WHERE s2.year = s.year AND s2.day = s.day AND s2.part = s.part
+                   AND r2.profile = r.profile
+                 ORDER BY r2.id DESC
+                 LIMIT 1
+             )
+             FROM results s
+             JOIN runs r ON r.id = s.run_id
+             WHERE s.year = ? AND r.profile = ?
+             GROUP BY s.day, s.part
+             ORDER BY s.day, s.part",
+        )?;
+
+        let progress = stmt
+            .query_map(params![year, profile], |row| {
+                Ok(PartProgress {
+                    day: row.get(0)?,
+                    part: row.get(1)?,
+                    solved: row.get(2)?,
+                    latest_status: row.get(3)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(progress)
+    }
+
+    /// Both parts of the last `limit` runs of a profile that included the day,
+    /// oldest first.
+    pub fn day(
+        &self,
+        year: u32,
+        day: u32,
+        profile: &str,
+        limit: usize,
+    ) -> AocResult<Vec<HistoryEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT r.id, datetime(r.started_at, 'unixepoch'), r.revision, r.machine,
+                    s.part, s.status, s.answer, s.duration_ns
+             FROM results s
+             JOIN runs r ON r.id = s.run_id
+             WHERE s.year = ?1 AND s.day = ?2 AND r.id IN (
+                 SELECT s2.run_id FROM results s2
+                 JOIN runs r2 ON r2.id = s2.run_id
+                 WHERE s2.year = ?1 AND s2.day = ?2 AND r2.profile = ?3
+                 GROUP BY s2.run_id
+                 ORDER BY s2.run_id DESC
+                 LIMIT ?4
+             )
+             ORDER BY s.part, r.id",
+        )?;
+
+        let entries = stmt
+            .query_map(params![year, day, profile, limit as i64], |row| {
+                Ok(HistoryEntry {
+                    run_id: row.get(0)?,
+                    started_at: row.get(1)?,
+                    revision: row.get(2)?,
+                    machine: row.get(3)?,
+                    part: row.get(4)?,
+                    status: row.get(5)?,
+                    answer: row.get(6)?,
+                    duration: Duration::from_nanos(row.get::<_, i64>(7)? as u64),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(entries)
+    }
+}
+
+/// The timing trend of each part, with the change against the previous run and
+/// a note whenever a solved answer differs from the last solved one.
+pub fn render_trend(entries: &[HistoryEntry]) -> String {
+    let mut output = String::new();
+
+    for part in [1, 2] {
+        let _ = writeln!(output, "Part {}", part);
+        let mut previous: Option<&HistoryEntry> = None;
+        let mut last_answer: Option<&str> = None;
+
+        for entry in entries.iter().filter(|e| e.part == part) {
+            let _ = write!(
+                output,
+                "  #{:<4} {}  {:<14} {:>10.2?}",
+                entry.run_id, entry.started_at, entry.revision, entry.duration
+            );
+
+            if let Some(previous) = previous.filter(|p| !p.duration.is_zero()) {
+                let change =
+                    (entry.duration.as_secs_f64() / previous.duration.as_secs_f64() - 1.0) * 100.0;
+                let _ = write!(output, " {:>+8.1}%", change);
+            } else {
+                let _ = write!(output, " {:>9}", "");
+            }
+
+            if entry.status != "solved" {
+                let _ = write!(output, "  {}", entry.status);
+            }
+
+            if previous.is_some_and(|p| p.machine != entry.machine) {
+                let _ = write!(output, "  on {}", entry.machine);
+            }
+
+            if let Some(answer) = entry.answer.as_deref() {
+                if let Some(last) = last_answer.filter(|last| *last != answer) {
+                    let _ = write!(output, "  answer changed: {} -> {}", last, answer);
+                }
+                last_answer = Some(answer);
+            }
+
+            output.push('\n');
+            previous = Some(entry);
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DEFAULT_PROFILE;
+    use crate::runner::{PartReport, PartStatus};
+
+    fn report(answer: &str, millis: u64) -> DayReport {
+        DayReport {
+            year: 2024,
+            day: 5,
+            part_1: PartReport {
+                status: PartStatus::Solved(answer.to_string()),
+                duration: Duration::from_millis(millis),
+            },
+            part_2: PartReport {
+                status: PartStatus::TimedOut,
+                duration: Duration::from_secs(1),
+            },
+        }
+    }
+
+    #[test]
+    fn record_and_trend_test() -> AocResult<()> {
+        let mut history = History::open(Path::new(":memory:"))?;
+        let info = RunInfo {
+            revision: String::from("abc1234"),
+            machine: String::from("test"),
+            profile: String::from(DEFAULT_PROFILE),
+        };
+        let alice = RunInfo {
+            profile: String::from("alice"),
+            ..info.clone()
+        };
+
+        history.record(&info, &[report("143", 10)])?;
+        history.record(&alice, &[report("150", 1)])?;
+        history.record(&info, &[report("143", 5)])?;
+        history.record(&info, &[report("144", 5)])?;
+
+        let entries = history.day(2024, 5, DEFAULT_PROFILE, 2)?;
+        assert_eq!(entries.len(), 4);
+        assert_eq!(entries[0].run_id, 3);
+        assert_eq!(entries[1].answer.as_deref(), Some("144"));
+        assert_eq!(entries[3].status, "timed_out");
+        assert!(history.day(2024, 6, DEFAULT_PROFILE, 10)?.is_empty());
+
+        let trend = render_trend(&history.day(2024, 5, DEFAULT_PROFILE, 10)?);
+        assert!(trend.contains("-50.0%"));
+        assert!(trend.contains("answer changed: 143 -> 144"));
+        assert_eq!(trend.matches("answer changed").count(), 1);
+
+        assert_eq!(
+            history.recorded_answer(2024, 5, 1, "alice")?.as_deref(),
+            Some("150")
+        );
+        assert_eq!(history.recorded_answer(2024, 5, 2, "alice")?, None);
+        assert_eq!(history.recorded_answer(2024, 5, 1, "bob")?, None);
+
+        let progress = history.progress(2024, DEFAULT_PROFILE)?;
+        assert_eq!(progress.len(), 2);
+        assert!(progress[0].solved);
+        assert!(!progress[1].solved);
+        assert_eq!(progress[1].latest_status, "timed_out");
+
+        Ok(())
+    }
+}
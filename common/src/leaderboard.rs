@@ -0,0 +1,264 @@
+use crate::error::{AocError, AocResult, Context};
+use crate::file::cache_dir;
+use log::warn;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Advent of Code asks for private leaderboards to be polled at most this often.
+pub const POLL_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+#[derive(Deserialize, Debug, Clone, Copy)]
+pub struct Star {
+    pub get_star_ts: i64,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub stars: u32,
+    pub local_score: u64,
+    /// Day, then part, to the moment the star was earned.
+    #[serde(default)]
+    pub completion_day_level: BTreeMap<u32, BTreeMap<u32, Star>>,
+}
+
+impl Member {
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    /// How long after the puzzle unlocked each part was solved.
+    pub fn solve_times(&self, year: u32, day: u32) -> (Option<Duration>, Option<Duration>) {
+        let unlock = unlock_timestamp(year, day);
+        let part = |part| {
+            self.completion_day_level
+                .get(&day)
+                .and_then(|parts| parts.get(&part))
+                .map(|star| Duration::from_secs((star.get_star_ts - unlock).max(0) as u64))
+        };
+
+        (part(1), part(2))
+    }
+}
+
+/// The JSON served at `/<year>/leaderboard/private/view/<id>.json`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct Leaderboard {
+    pub event: String,
+    pub members: BTreeMap<String, Member>,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> AocResult<Leaderboard> {
+        serde_json::from_str(json).map_err(|e| AocError::parse(format!("leaderboard: {}", e)))
+    }
+
+    /// Members by local score, then stars, best first.
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut members: Vec<&Member> = self.members.values().collect();
+        members.sort_by(|a, b| {
+            b.local_score
+                .cmp(&a.local_score)
+                .then(b.stars.cmp(&a.stars))
+                .then(a.id.cmp(&b.id))
+        });
+        members
+    }
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+pub(crate) fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Puzzles unlock at midnight EST, i.e. 05:00 UTC.
+fn unlock_timestamp(year: u32, day: u32) -> i64 {
+    days_from_civil(year as i64, 12, day as i64) * 86_400 + 5 * 3_600
+}
+
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3_600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+fn cell(duration: Option<Duration>) -> String {
+    duration.map_or_else(|| String::from("-"), format_duration)
+}
+
+/// Stars and score of every member, followed by their solve times per day and
+/// how long part 2 took after part 1.
+pub fn render(leaderboard: &Leaderboard, year: u32) -> String {
+    let mut output = String::new();
+    let _ = writeln!(
+        output,
+        "Private leaderboard {} ({} members)",
+        leaderboard.event,
+        leaderboard.members.len()
+    );
+
+    for (rank, member) in leaderboard.ranking().into_iter().enumerate() {
+        let _ = writeln!(
+            output,
+            "\n{:>3}) {}  {} points  {} stars",
+            rank + 1,
+            member.display_name(),
+            member.local_score,
+            member.stars
+        );
+        if member.completion_day_level.is_empty() {
+            continue;
+        }
+
+        let _ = writeln!(
+            output,
+            "     {:>3}  {:>10}  {:>10}  {:>10}",
+            "Day", "Part 1", "Part 2", "Delta"
+        );
+        for &day in member.completion_day_level.keys() {
+            let (part_1, part_2) = member.solve_times(year, day);
+            // Clamped or hand-edited timestamps can put part 2 before part 1.
+            let delta = part_1
+                .zip(part_2)
+                .and_then(|(part_1, part_2)| part_2.checked_sub(part_1))
+                .map(|delta| format!("+{}", format_duration(delta)));
+            let _ = writeln!(
+                output,
+                "     {:>3}  {:>10}  {:>10}  {:>10}",
+                day,
+                cell(part_1),
+                cell(part_2),
+                delta.as_deref().unwrap_or("-")
+            );
+        }
+    }
+
+    output
+}
+
+fn cache_path(year: u32, id: u64) -> AocResult<PathBuf> {
+    let dir = cache_dir().ok_or_else(|| AocError::Config(String::from("no cache directory")))?;
+    Ok(dir.join(format!("leaderboard/{}_{}.json", year, id)))
+}
+
+fn is_fresh(path: &Path) -> bool {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+        .and_then(|modified| modified.elapsed().ok())
+        .is_some_and(|age| age < POLL_INTERVAL)
+}
+
+async fn download(year: u32, id: u64, cookie: &str) -> AocResult<String> {
+    let response = reqwest::Client::new()
+        .get(format!(
+            "https://adventofcode.com/{}/leaderboard/private/view/{}.json",
+            year, id
+        ))
+        .header("Cookie", cookie)
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(AocError::Download(format!(
+            "{} for leaderboard {} of {}",
+            response.status(),
+            id,
+            year
+        )));
+    }
+
+    // An expired cookie gets the login page instead of JSON.
+    let json = response.text().await?;
+    Leaderboard::parse(&json)?;
+
+    Ok(json)
+}
+
+/// The leaderboard from the local cache, downloading it again only once the
+/// cached copy is older than [`POLL_INTERVAL`].
+pub async fn fetch(year: u32, id: u64, cookie: &str) -> AocResult<Leaderboard> {
+    let path = cache_path(year, id)?;
+
+    if !is_fresh(&path) {
+        match download(year, id, cookie).await {
+            Ok(json) => {
+                if let Some(dir) = path.parent() {
+                    fs::create_dir_all(dir)
+                        .with_context(|| format!("creating {}", dir.display()))?;
+                }
+                fs::write(&path, json).with_context(|| format!("writing {}", path.display()))?;
+            }
+            Err(e) if path.exists() => warn!("{}, showing the cached leaderboard", e),
+            Err(e) => return Err(e),
+        }
+    }
+
+    let json = fs::read_to_string(&path).with_context(|| format!("reading {}", path.display()))?;
+    Leaderboard::parse(&json)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = include_str!("../fixtures/leaderboard.json");
+
+    #[test]
+    fn unlock_test() {
+        assert_eq!(unlock_timestamp(2024, 1), 1_733_029_200);
+        assert_eq!(unlock_timestamp(2023, 25), 1_703_480_400);
+    }
+
+    #[test]
+    fn fixture_test() -> AocResult<()> {
+        let leaderboard = Leaderboard::parse(FIXTURE)?;
+        let ranking = leaderboard.ranking();
+        assert_eq!(
+            ranking.iter().map(|m| m.id).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+
+        let alice = ranking[0];
+        assert_eq!(
+            alice.solve_times(2024, 1),
+            (
+                Some(Duration::from_secs(312)),
+                Some(Duration::from_secs(570))
+            )
+        );
+        assert_eq!(alice.solve_times(2024, 2).1, None);
+        assert_eq!(ranking[1].display_name(), "(anonymous user #2)");
+
+        let report = render(&leaderboard, 2024);
+        assert!(report.contains("  1) alice  7 points  3 stars"));
+        assert!(report.contains("  1    00:05:12    00:09:30   +00:04:18"));
+        assert!(report.contains("  2    25:01:01           -           -"));
+        assert!(report.contains("  3    00:05:00    00:01:40           -"));
+        assert!(report.contains("  3) carol  0 points  0 stars\n"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn login_page_test() {
+        assert!(Leaderboard::parse("<!DOCTYPE html>").is_err());
+    }
+}
@@ -1,4 +1,5 @@
 pub mod grid;
+pub mod grid3;
 pub mod utils;
 
 pub mod graph;
@@ -7,6 +8,18 @@ pub mod dsu;
 
 pub mod file;
 
+pub mod params;
+pub mod parse;
+
 pub mod base_day;
 pub mod config;
+pub mod error;
+pub mod examples;
+pub mod history;
+pub mod leaderboard;
+pub mod render;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod status;
 pub mod test_utils;
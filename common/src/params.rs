@@ -0,0 +1,93 @@
+use crate::error::{AocError, AocResult};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A single parameter value as written in TOML.
+#[derive(Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Param {
+    Integer(i64),
+    Float(f64),
+    Bool(bool),
+    Text(String),
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Param::Integer(n) => write!(f, "{}", n),
+            Param::Float(n) => write!(f, "{}", n),
+            Param::Bool(b) => write!(f, "{}", b),
+            Param::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Puzzle constants that differ between the examples and the real input, such as
+/// grid sizes or thresholds. Days read them in [`crate::base_day::BaseDay::apply_params`]
+/// and keep their own defaults for anything missing.
+#[derive(Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(transparent)]
+pub struct DayParams {
+    values: BTreeMap<String, Param>,
+}
+
+impl DayParams {
+    pub fn new() -> DayParams {
+        DayParams::default()
+    }
+
+    pub fn insert(&mut self, key: impl Into<String>, value: Param) {
+        self.values.insert(key.into(), value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The value of `key` converted with `FromStr`, if it was given.
+    pub fn get<T>(&self, key: &str) -> AocResult<Option<T>>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let Some(value) = self.values.get(key) else {
+            return Ok(None);
+        };
+
+        value
+            .to_string()
+            .parse()
+            .map(Some)
+            .map_err(|e| AocError::parse(format!("parameter {} = {}: {}", key, value, e)))
+    }
+
+    pub fn get_or<T>(&self, key: &str, default: T) -> AocResult<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        Ok(self.get(key)?.unwrap_or(default))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn typed_access_test() -> AocResult<()> {
+        let mut params = DayParams::new();
+        params.insert("grid_size", Param::Integer(6));
+        params.insert("name", Param::Text(String::from("example")));
+
+        assert_eq!(params.get::<usize>("grid_size")?, Some(6));
+        assert_eq!(params.get_or::<i32>("missing", 70)?, 70);
+        assert_eq!(params.get::<String>("name")?.as_deref(), Some("example"));
+        assert!(params.get::<u32>("name").is_err());
+
+        Ok(())
+    }
+}
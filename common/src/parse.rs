@@ -0,0 +1,305 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::str::FromStr;
+
+const PLACEHOLDER: &str = "{}";
+
+/// Error produced while parsing puzzle input. Lines and columns are 1-based.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// Extracts every (optionally negative) integer of a line, in order.
+pub fn ints<T>(line: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = line.as_bytes();
+    let mut result = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let negative =
+            bytes[index] == b'-' && bytes.get(index + 1).is_some_and(|b| b.is_ascii_digit());
+        if !negative && !bytes[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        index += 1;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+
+        result.push(parse_field(&line[start..index], 1, start + 1)?);
+    }
+
+    Ok(result)
+}
+
+/// Splits the input into blocks separated by one or more blank lines.
+pub fn blocks(input: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(block_start) = start.take() {
+                result.push(&input[block_start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end_matches(['\n', '\r']).len();
+        }
+        offset += line.len();
+    }
+
+    if let Some(block_start) = start {
+        result.push(&input[block_start..end]);
+    }
+
+    result
+}
+
+/// Splits a line once on `separator`, trimming both sides.
+pub fn split_pair<'a>(line: &'a str, separator: &str) -> Result<(&'a str, &'a str), ParseError> {
+    line.split_once(separator)
+        .map(|(left, right)| (left.trim(), right.trim()))
+        .ok_or_else(|| ParseError::new(1, 1, format!("expected '{}' in '{}'", separator, line)))
+}
+
+/// Parses a `key: value` line.
+pub fn key_value(line: &str) -> Result<(&str, &str), ParseError> {
+    split_pair(line, ":")
+}
+
+/// Parses an `a -> b` line.
+pub fn arrow(line: &str) -> Result<(&str, &str), ParseError> {
+    split_pair(line, "->")
+}
+
+fn parse_field<T>(value: &str, line: usize, column: usize) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| ParseError::new(line, column, format!("invalid value '{}': {}", value, e)))
+}
+
+/// Values captured by a [`Pattern`], converted from their `(column, text)` fields.
+pub trait FromFields: Sized {
+    const FIELDS: usize;
+
+    fn from_fields(fields: &[(usize, &str)]) -> Result<Self, ParseError>;
+}
+
+macro_rules! impl_from_fields {
+    ($count:expr; $($name:ident: $index:tt),+) => {
+        impl<$($name),+> FromFields for ($($name,)+)
+        where
+            $($name: FromStr, $name::Err: Display,)+
+        {
+            const FIELDS: usize = $count;
+
+            fn from_fields(fields: &[(usize, &str)]) -> Result<Self, ParseError> {
+                Ok(($(parse_field::<$name>(fields[$index].1, 1, fields[$index].0)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_fields!(1; A: 0);
+impl_from_fields!(2; A: 0, B: 1);
+impl_from_fields!(3; A: 0, B: 1, C: 2);
+impl_from_fields!(4; A: 0, B: 1, C: 2, D: 3);
+impl_from_fields!(5; A: 0, B: 1, C: 2, D: 3, E: 4);
+impl_from_fields!(6; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5);
+impl_from_fields!(7; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6);
+impl_from_fields!(8; A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7);
+
+/// A declarative line format where each `{}` captures a field, e.g. `"p={},{} v={},{}"`.
+///
+/// A field extends up to the next occurrence of the literal text following it,
+/// or to the end of the line for a trailing placeholder.
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    literals: Vec<String>,
+}
+
+impl Pattern {
+    pub fn new(pattern: &str) -> Pattern {
+        Pattern {
+            literals: pattern.split(PLACEHOLDER).map(String::from).collect(),
+        }
+    }
+
+    pub fn fields(&self) -> usize {
+        self.literals.len() - 1
+    }
+
+    pub fn captures<'a>(&self, line: &'a str) -> Result<Vec<(usize, &'a str)>, ParseError> {
+        let mut fields = Vec::with_capacity(self.fields());
+        let mut position = 0;
+
+        for (index, literal) in self.literals.iter().enumerate() {
+            if index > 0 {
+                let end = if literal.is_empty() {
+                    if index == self.literals.len() - 1 {
+                        line.len()
+                    } else {
+                        return Err(ParseError::new(
+                            1,
+                            position + 1,
+                            "adjacent placeholders are ambiguous",
+                        ));
+                    }
+                } else {
+                    line[position..]
+                        .find(literal.as_str())
+                        .map(|offset| position + offset)
+                        .ok_or_else(|| {
+                            ParseError::new(1, position + 1, format!("expected '{}'", literal))
+                        })?
+                };
+                fields.push((position + 1, &line[position..end]));
+                position = end;
+            }
+
+            if !line[position..].starts_with(literal.as_str()) {
+                return Err(ParseError::new(
+                    1,
+                    position + 1,
+                    format!("expected '{}'", literal),
+                ));
+            }
+            position += literal.len();
+        }
+
+        if position != line.len() {
+            return Err(ParseError::new(
+                1,
+                position + 1,
+                format!("unexpected trailing input '{}'", &line[position..]),
+            ));
+        }
+
+        Ok(fields)
+    }
+
+    pub fn parse<T: FromFields>(&self, line: &str) -> Result<T, ParseError> {
+        if T::FIELDS != self.fields() {
+            return Err(ParseError::new(
+                1,
+                1,
+                format!(
+                    "pattern has {} fields but {} were requested",
+                    self.fields(),
+                    T::FIELDS
+                ),
+            ));
+        }
+
+        T::from_fields(&self.captures(line)?)
+    }
+
+    /// Parses every non-empty line, reporting errors with their line number.
+    pub fn parse_lines<T: FromFields>(&self, input: &str) -> Result<Vec<T>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| self.parse(line).map_err(|e| e.at_line(index + 1)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ints_test() {
+        let result = ints::<i64>("p=0,4 v=3,-3 and x-12").unwrap();
+        assert_eq!(result, vec![0, 4, 3, -3, -12]);
+    }
+
+    #[test]
+    fn ints_overflow_test() {
+        let error = ints::<u8>("1 300").unwrap_err();
+        assert_eq!(error.column, 3);
+    }
+
+    #[test]
+    fn blocks_test() {
+        let input = "a\nb\n\n\nc\r\n \nd\n";
+        assert_eq!(blocks(input), vec!["a\nb", "c", "d"]);
+    }
+
+    #[test]
+    fn key_value_and_arrow_test() {
+        assert_eq!(key_value("Game 1: 3 blue").unwrap(), ("Game 1", "3 blue"));
+        assert_eq!(arrow("x00 AND y00 -> z00").unwrap(), ("x00 AND y00", "z00"));
+        assert!(arrow("x00").is_err());
+    }
+
+    #[test]
+    fn pattern_test() {
+        let pattern = Pattern::new("p={},{} v={},{}");
+        let result: (i64, i64, i64, i64) = pattern.parse("p=0,4 v=3,-3").unwrap();
+        assert_eq!(result, (0, 4, 3, -3));
+    }
+
+    #[test]
+    fn pattern_errors_test() {
+        let pattern = Pattern::new("p={},{} v={},{}");
+
+        let error = pattern
+            .parse_lines::<(i64, i64, i64, i64)>("p=0,4 v=3,-3\np=1,x v=1,1")
+            .unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+
+        let error = pattern.parse::<(i64, i64)>("p=0,4 v=3,-3").unwrap_err();
+        assert_eq!(error.column, 1);
+
+        let error = pattern
+            .parse::<(i64, i64, i64, i64)>("p=0,4 w=3,-3")
+            .unwrap_err();
+        assert_eq!(error.column, 5);
+    }
+}
@@ -0,0 +1,322 @@
+use crate::error::{AocError, AocResult, Context};
+use crate::grid::{Grid, Point};
+use std::collections::HashMap;
+use std::fmt::{Debug, Display, Write as _};
+use std::fs::File;
+use std::hash::Hash;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+/// A colour as red, green and blue.
+pub type Rgb = [u8; 3];
+
+/// Cells missing from the grid are drawn in this colour.
+pub const BACKGROUND: Rgb = [0, 0, 0];
+
+/// The grid as RGB pixels, row by row, with every cell drawn as a `scale` by
+/// `scale` square. Returns the width and height in pixels along with them.
+pub fn to_pixels<T>(grid: &Grid<T>, scale: u32, colour: impl Fn(&T) -> Rgb) -> (u32, u32, Vec<u8>)
+where
+    T: PartialEq + Debug + Eq + Hash + Clone + Display,
+{
+    let scale = scale.max(1);
+    let width = grid.cols().max(0) as u32 * scale;
+    let height = grid.rows().max(0) as u32 * scale;
+
+    let mut pixels = Vec::with_capacity((width * height * 3) as usize);
+    for row in 0..grid.rows() {
+        let mut line = Vec::with_capacity((width * 3) as usize);
+        for col in 0..grid.cols() {
+            let rgb = grid
+                .get(Point { x: row, y: col })
+                .map_or(BACKGROUND, &colour);
+            for _ in 0..scale {
+                line.extend_from_slice(&rgb);
+            }
+        }
+        for _ in 0..scale {
+            pixels.extend_from_slice(&line);
+        }
+    }
+
+    (width, height, pixels)
+}
+
+fn create(path: &Path) -> AocResult<BufWriter<File>> {
+    if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    }
+    let file = File::create(path).with_context(|| format!("creating {}", path.display()))?;
+
+    Ok(BufWriter::new(file))
+}
+
+/// Draws the grid into a PNG file.
+pub fn save_png<T>(
+    grid: &Grid<T>,
+    path: &Path,
+    scale: u32,
+    colour: impl Fn(&T) -> Rgb,
+) -> AocResult<()>
+where
+    T: PartialEq + Debug + Eq + Hash + Clone + Display,
+{
+    let (width, height, pixels) = to_pixels(grid, scale, colour);
+    if width == 0 || height == 0 {
+        return Err(AocError::other("cannot draw an empty grid"));
+    }
+
+    let mut encoder = png::Encoder::new(create(path)?, width, height);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(AocError::other)?;
+    writer.write_image_data(&pixels).map_err(AocError::other)?;
+    writer
+        .finish()
+        .map_err(AocError::other)
+        .with_context(|| format!("writing {}", path.display()))
+}
+
+/// Builds an animated GIF one simulation step at a time. Every frame must
+/// have the size of the first one.
+pub struct GifWriter {
+    encoder: gif::Encoder<BufWriter<File>>,
+    width: u16,
+    height: u16,
+    scale: u32,
+    /// Time between frames, in hundredths of a second.
+    delay: u16,
+}
+
+impl GifWriter {
+    pub fn create(
+        path: &Path,
+        rows: i32,
+        cols: i32,
+        scale: u32,
+        delay: Duration,
+    ) -> AocResult<GifWriter> {
+        let scale = scale.max(1);
+        let size = |cells: i32| {
+            u16::try_from(cells.max(0) as u64 * scale as u64)
+                .ok()
+                .filter(|&pixels| pixels > 0)
+                .ok_or_else(|| AocError::other(format!("a GIF cannot be {} cells wide", cells)))
+        };
+        let (width, height) = (size(cols)?, size(rows)?);
+
+        let mut encoder =
+            gif::Encoder::new(create(path)?, width, height, &[]).map_err(AocError::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(AocError::other)?;
+
+        Ok(GifWriter {
+            encoder,
+            width,
+            height,
+            scale,
+            delay: (delay.as_millis() / 10).min(u16::MAX as u128) as u16,
+        })
+    }
+
+    pub fn add_frame<T>(&mut self, grid: &Grid<T>, colour: impl Fn(&T) -> Rgb) -> AocResult<()>
+    where
+        T: PartialEq + Debug + Eq + Hash + Clone + Display,
+    {
+        let (width, height, pixels) = to_pixels(grid, self.scale, colour);
+        if (width, height) != (self.width as u32, self.height as u32) {
+            return Err(AocError::other(format!(
+                "frame is {}x{} pixels, the GIF is {}x{}",
+                width, height, self.width, self.height
+            )));
+        }
+
+        // Grids rarely use many colours, so they usually fit an exact palette.
+        let mut palette: HashMap<Rgb, u8> = HashMap::new();
+        let mut indices = Vec::with_capacity(pixels.len() / 3);
+        for rgb in pixels.chunks_exact(3) {
+            let rgb = [rgb[0], rgb[1], rgb[2]];
+            let next = palette.len();
+            if next > u8::MAX as usize && !palette.contains_key(&rgb) {
+                indices.clear();
+                break;
+            }
+            indices.push(*palette.entry(rgb).or_insert(next as u8));
+        }
+
+        let mut frame = if indices.is_empty() {
+            gif::Frame::from_rgb_speed(self.width, self.height, &pixels, 10)
+        } else {
+            let mut colours = vec![0; palette.len() * 3];
+            for (rgb, index) in palette {
+                colours[index as usize * 3..index as usize * 3 + 3].copy_from_slice(&rgb);
+            }
+            gif::Frame::from_palette_pixels(self.width, self.height, indices, colours, None)
+        };
+        frame.delay = self.delay;
+
+        self.encoder.write_frame(&frame).map_err(AocError::other)
+    }
+
+    pub fn finish(self) -> AocResult<()> {
+        let mut file = self.encoder.into_inner().map_err(AocError::other)?;
+        file.flush()?;
+
+        Ok(())
+    }
+}
+
+/// The grid in terminal colours. Each character is an upper half block
+/// showing two rows, so cells come out roughly square.
+pub fn to_ansi<T>(grid: &Grid<T>, colour: impl Fn(&T) -> Rgb) -> String
+where
+    T: PartialEq + Debug + Eq + Hash + Clone + Display,
+{
+    let cell = |row, col| {
+        grid.get(Point { x: row, y: col })
+            .map_or(BACKGROUND, &colour)
+    };
+
+    let mut output = String::new();
+    for row in (0..grid.rows()).step_by(2) {
+        for col in 0..grid.cols() {
+            let [r, g, b] = cell(row, col);
+            let [br, bg, bb] = if row + 1 < grid.rows() {
+                cell(row + 1, col)
+            } else {
+                BACKGROUND
+            };
+            let _ = write!(
+                output,
+                "\x1b[38;2;{};{};{}m\x1b[48;2;{};{};{}m▀",
+                r, g, b, br, bg, bb
+            );
+        }
+        output.push_str("\x1b[0m\n");
+    }
+
+    output
+}
+
+/// Plays frames in a terminal, drawing each over the previous one.
+pub struct TerminalAnimation<W: Write> {
+    out: W,
+    delay: Duration,
+    started: bool,
+}
+
+impl TerminalAnimation<std::io::Stdout> {
+    pub fn stdout(delay: Duration) -> TerminalAnimation<std::io::Stdout> {
+        TerminalAnimation::new(std::io::stdout(), delay)
+    }
+}
+
+impl<W: Write> TerminalAnimation<W> {
+    pub fn new(out: W, delay: Duration) -> TerminalAnimation<W> {
+        TerminalAnimation {
+            out,
+            delay,
+            started: false,
+        }
+    }
+
+    /// Shows one frame with a caption under it, then waits for the delay.
+    pub fn show<T>(
+        &mut self,
+        grid: &Grid<T>,
+        caption: &str,
+        colour: impl Fn(&T) -> Rgb,
+    ) -> AocResult<()>
+    where
+        T: PartialEq + Debug + Eq + Hash + Clone + Display,
+    {
+        if !self.started {
+            // Clear the screen once, later frames only move the cursor back.
+            self.out.write_all(b"\x1b[2J")?;
+            self.started = true;
+        }
+        writeln!(self.out, "\x1b[H{}{}\x1b[K", to_ansi(grid, colour), caption)?;
+        self.out.flush()?;
+
+        if !self.delay.is_zero() {
+            thread::sleep(self.delay);
+        }
+
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn colour(c: &char) -> Rgb {
+        match c {
+            '#' => [255, 255, 255],
+            _ => [0, 0, 128],
+        }
+    }
+
+    fn grid(rows: &[&str]) -> Grid<char> {
+        let cells: Vec<Vec<char>> = rows.iter().map(|row| row.chars().collect()).collect();
+        Grid::from_vector(&cells)
+    }
+
+    #[test]
+    fn pixels_test() {
+        let (width, height, pixels) = to_pixels(&grid(&["#.", ".."]), 2, colour);
+
+        assert_eq!((width, height), (4, 4));
+        assert_eq!(pixels.len(), 4 * 4 * 3);
+        assert_eq!(&pixels[0..6], &[255, 255, 255, 255, 255, 255]);
+        assert_eq!(&pixels[6..9], &[0, 0, 128]);
+        assert_eq!(&pixels[12..15], &[255, 255, 255]);
+        assert_eq!(&pixels[24..27], &[0, 0, 128]);
+    }
+
+    #[test]
+    fn files_test() -> AocResult<()> {
+        let dir = env::temp_dir().join(format!("aoc-render-{}", std::process::id()));
+        let png = dir.join("grid.png");
+        save_png(&grid(&["#.", ".#"]), &png, 3, colour)?;
+        assert!(std::fs::read(&png)?.starts_with(b"\x89PNG"));
+
+        let gif = dir.join("frames.gif");
+        let mut writer = GifWriter::create(&gif, 2, 2, 1, Duration::from_millis(100))?;
+        writer.add_frame(&grid(&["#.", ".."]), colour)?;
+        writer.add_frame(&grid(&[".#", ".."]), colour)?;
+        assert!(writer.add_frame(&grid(&["#"]), colour).is_err());
+        writer.finish()?;
+        assert!(std::fs::read(&gif)?.starts_with(b"GIF89a"));
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+
+    #[test]
+    fn ansi_test() -> AocResult<()> {
+        let ansi = to_ansi(&grid(&["#", ".", "#"]), colour);
+        assert_eq!(
+            ansi,
+            "\x1b[38;2;255;255;255m\x1b[48;2;0;0;128m▀\x1b[0m\n\
+             \x1b[38;2;255;255;255m\x1b[48;2;0;0;0m▀\x1b[0m\n"
+        );
+
+        let mut animation = TerminalAnimation::new(Vec::new(), Duration::ZERO);
+        animation.show(&grid(&["#"]), "step 1", colour)?;
+        animation.show(&grid(&["."]), "step 2", colour)?;
+        let out = String::from_utf8(animation.into_inner()).unwrap();
+        assert_eq!(out.matches("\x1b[2J").count(), 1);
+        assert!(out.ends_with("step 2\x1b[K\n"));
+
+        Ok(())
+    }
+}
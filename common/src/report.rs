@@ -0,0 +1,191 @@
+use crate::runner::{DayReport, PartReport, PartStatus};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::fmt::Write;
+use std::time::Duration;
+
+/// Output formats for the summary printed after a run.
+#[derive(ValueEnum, Debug, Clone, Copy, Eq, PartialEq)]
+pub enum Format {
+    Json,
+    Csv,
+    Markdown,
+}
+
+#[derive(Serialize)]
+struct PartRecord<'a> {
+    status: &'static str,
+    answer: Option<&'a str>,
+    error: Option<&'a str>,
+    duration_ms: f64,
+}
+
+#[derive(Serialize)]
+struct DayRecord<'a> {
+    year: u32,
+    day: u32,
+    part_1: PartRecord<'a>,
+    part_2: PartRecord<'a>,
+}
+
+fn millis(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+impl<'a> From<&'a PartReport> for PartRecord<'a> {
+    fn from(part: &'a PartReport) -> Self {
+        PartRecord {
+            status: part.status.kind(),
+            answer: part.status.answer(),
+            error: part.status.error(),
+            duration_ms: millis(part.duration),
+        }
+    }
+}
+
+impl<'a> From<&'a DayReport> for DayRecord<'a> {
+    fn from(report: &'a DayReport) -> Self {
+        DayRecord {
+            year: report.year,
+            day: report.day,
+            part_1: (&report.part_1).into(),
+            part_2: (&report.part_2).into(),
+        }
+    }
+}
+
+pub fn render(reports: &[DayReport], format: Format) -> String {
+    match format {
+        Format::Json => to_json(reports),
+        Format::Csv => to_csv(reports),
+        Format::Markdown => to_markdown(reports),
+    }
+}
+
+pub fn to_json(reports: &[DayReport]) -> String {
+    let records: Vec<DayRecord> = reports.iter().map(DayRecord::from).collect();
+    serde_json::to_string_pretty(&records).expect("reports are always serialisable")
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// One row per part, so answers and timings can be diffed line by line.
+pub fn to_csv(reports: &[DayReport]) -> String {
+    let mut output = String::from("year,day,part,status,answer,duration_ms,error\n");
+    for report in reports {
+        for (part, result) in [(1, &report.part_1), (2, &report.part_2)] {
+            let _ = writeln!(
+                output,
+                "{},{},{},{},{},{:.3},{}",
+                report.year,
+                report.day,
+                part,
+                result.status.kind(),
+                csv_field(result.status.answer().unwrap_or_default()),
+                millis(result.duration),
+                csv_field(result.status.error().unwrap_or_default()),
+            );
+        }
+    }
+
+    output
+}
+
+fn markdown_cell(part: &PartReport) -> String {
+    let text = match &part.status {
+        PartStatus::Solved(answer) => format!("`{}`", answer),
+        other => format!("_{}_", other.kind().replace('_', " ")),
+    };
+    text.replace('|', "\\|")
+}
+
+/// A timing table for the README. The last row adds up the time of every part,
+/// which is more than the run took when days ran in parallel with `--jobs`.
+pub fn to_markdown(reports: &[DayReport]) -> String {
+    let mut output = String::from("| Day | Part 1 | Time | Part 2 | Time |\n");
+    output.push_str("|----:|--------|-----:|--------|-----:|\n");
+
+    let mut total = Duration::ZERO;
+    for report in reports {
+        total += report.part_1.duration + report.part_2.duration;
+        let _ = writeln!(
+            output,
+            "| {} | {} | {:.2?} | {} | {:.2?} |",
+            report.day,
+            markdown_cell(&report.part_1),
+            report.part_1.duration,
+            markdown_cell(&report.part_2),
+            report.part_2.duration,
+        );
+    }
+    let _ = writeln!(output, "| **Total (sum of parts)** | | | | {:.2?} |", total);
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reports() -> Vec<DayReport> {
+        vec![
+            DayReport {
+                year: 2024,
+                day: 1,
+                part_1: PartReport {
+                    status: PartStatus::Solved(String::from("11")),
+                    duration: Duration::from_millis(2),
+                },
+                part_2: PartReport {
+                    status: PartStatus::Solved(String::from("31")),
+                    duration: Duration::from_millis(1),
+                },
+            },
+            DayReport {
+                year: 2024,
+                day: 2,
+                part_1: PartReport {
+                    status: PartStatus::Errored(String::from("bad, \"input\"")),
+                    duration: Duration::ZERO,
+                },
+                part_2: PartReport {
+                    status: PartStatus::TimedOut,
+                    duration: Duration::from_secs(1),
+                },
+            },
+        ]
+    }
+
+    #[test]
+    fn json_test() {
+        let value: serde_json::Value = serde_json::from_str(&to_json(&reports())).unwrap();
+        assert_eq!(value[0]["part_1"]["answer"], "11");
+        assert_eq!(value[0]["part_2"]["duration_ms"], 1.0);
+        assert_eq!(value[1]["part_1"]["status"], "errored");
+        assert!(value[1]["part_1"]["answer"].is_null());
+    }
+
+    #[test]
+    fn csv_test() {
+        let csv = to_csv(&reports());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[1], "2024,1,1,solved,11,2.000,");
+        assert_eq!(lines[3], "2024,2,1,errored,,0.000,\"bad, \"\"input\"\"\"");
+        assert_eq!(lines[4], "2024,2,2,timed_out,,1000.000,timed out");
+    }
+
+    #[test]
+    fn markdown_test() {
+        let markdown = to_markdown(&reports());
+        assert!(markdown.contains("| 1 | `11` | 2.00ms | `31` | 1.00ms |"));
+        assert!(markdown.contains("| 2 | _errored_ | 0.00ns | _timed out_ | 1.00s |"));
+        assert!(markdown.ends_with("| **Total (sum of parts)** | | | | 1.00s |\n"));
+    }
+}
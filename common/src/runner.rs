@@ -0,0 +1,940 @@
+use crate::base_day::BaseDay;
+use crate::config::{DEFAULT_PROFILE, get_config};
+use crate::error::{AocError, AocResult};
+use crate::file::{
+    InputSource, InputStore, download_input_file, download_profile_input_file, get_data_dir,
+    migrate_inputs, project_root,
+};
+use crate::history::{History, RunInfo, render_trend, runs_db_path};
+use crate::leaderboard;
+use crate::report::{Format, render};
+use crate::scaffold;
+use crate::status::{day_statuses, render_calendar};
+use crate::utils::init_logger;
+use clap::{Parser, Subcommand};
+use futures::StreamExt;
+use futures::stream;
+use log::{error, info, warn};
+use std::any::Any;
+use std::cell::OnceCell;
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::num::NonZeroUsize;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::process::Command as ProcessCommand;
+use std::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Some solutions recurse deeply, so workers get more than the default 2 MiB stack.
+const WORKER_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+const RUNNING: u8 = 0;
+const FINISHED: u8 = 1;
+const ABANDONED: u8 = 2;
+
+/// Timed out days whose worker threads have not returned yet.
+static ABANDONED_WORKERS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static WORKER_STATE: OnceCell<Arc<AtomicU8>> = const { OnceCell::new() };
+}
+
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+pub struct Args {
+    /// Days to include in the run
+    #[arg(short, long)]
+    pub day: Option<u32>,
+
+    /// Wall-clock limit for each day, in seconds
+    #[arg(short, long, default_value_t = 60)]
+    pub timeout: u64,
+
+    /// Days to run at the same time; 0 uses every available core
+    #[arg(short, long, default_value_t = 1)]
+    pub jobs: usize,
+
+    /// Print a summary of the run to stdout in this format
+    #[arg(short, long, value_enum)]
+    pub format: Option<Format>,
+
+    /// Solve the selected day with this input instead of the downloaded one
+    #[arg(short, long, requires = "day", conflicts_with = "stdin")]
+    pub input: Option<PathBuf>,
+
+    /// Solve the selected day with input read from stdin
+    #[arg(long, requires = "day")]
+    pub stdin: bool,
+
+    /// Account from config.toml whose input is used
+    #[arg(short, long, global = true, default_value = DEFAULT_PROFILE)]
+    pub profile: String,
+
+    /// Run against every profile's stored input and compare with its recorded answers
+    #[arg(long, conflicts_with_all = ["profile", "input", "stdin", "format"])]
+    pub all_profiles: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Show the recorded timings and answers of a day
+    History {
+        day: u32,
+
+        /// Number of most recent runs to show
+        #[arg(short, long, default_value_t = 10)]
+        limit: usize,
+    },
+    /// Show a private leaderboard of this year, refreshed at most every 15 minutes
+    Leaderboard {
+        /// Leaderboard id, defaults to `leaderboard` in config.toml
+        id: Option<u64>,
+    },
+    /// Show which days of this year are solved, from the recorded runs
+    Status,
+    /// Create a day from the template, register it and fetch its input and example
+    NewDay {
+        day: u32,
+
+        /// Year crate to add the day to, defaults to this one
+        #[arg(short, long)]
+        year: Option<u32>,
+    },
+    /// Create a yearNNNN crate in the workspace, starting with day 1
+    NewYear { year: u32 },
+    /// Move this year's downloaded inputs from data/ into the configured input store
+    MigrateInputs,
+}
+
+impl Args {
+    fn input_source(&self) -> io::Result<Option<InputSource>> {
+        if self.stdin {
+            return InputSource::from_reader(io::stdin().lock()).map(Some);
+        }
+
+        Ok(self.input.clone().map(InputSource::Path))
+    }
+
+    fn worker_count(&self) -> usize {
+        match self.jobs {
+            0 => thread::available_parallelism().map_or(1, NonZeroUsize::get),
+            jobs => jobs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PartStatus {
+    Solved(String),
+    Errored(String),
+    Panicked(String),
+    TimedOut,
+    /// The day returned [`AocError::Unimplemented`] for this part.
+    Unimplemented,
+}
+
+impl Display for PartStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PartStatus::Solved(answer) => write!(f, "{:?}", answer),
+            PartStatus::Errored(msg) => write!(f, "errored: {}", msg),
+            PartStatus::Panicked(msg) => write!(f, "panicked: {}", msg),
+            PartStatus::TimedOut => write!(f, "timed out"),
+            PartStatus::Unimplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+impl PartStatus {
+    /// Stable, lowercase name of the outcome used in reports.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            PartStatus::Solved(_) => "solved",
+            PartStatus::Errored(_) => "errored",
+            PartStatus::Panicked(_) => "panicked",
+            PartStatus::TimedOut => "timed_out",
+            PartStatus::Unimplemented => "unimplemented",
+        }
+    }
+
+    pub fn answer(&self) -> Option<&str> {
+        match self {
+            PartStatus::Solved(answer) => Some(answer),
+            _ => None,
+        }
+    }
+
+    pub fn error(&self) -> Option<&str> {
+        match self {
+            PartStatus::Errored(msg) | PartStatus::Panicked(msg) => Some(msg),
+            PartStatus::Solved(_) | PartStatus::Unimplemented => None,
+            PartStatus::TimedOut => Some("timed out"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct PartReport {
+    pub status: PartStatus,
+    pub duration: Duration,
+}
+
+impl PartReport {
+    fn new(status: PartStatus, duration: Duration) -> Self {
+        PartReport { status, duration }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        matches!(self.status, PartStatus::Solved(_))
+    }
+
+    /// Solved, or deliberately left unimplemented.
+    pub fn is_ok(&self) -> bool {
+        matches!(
+            self.status,
+            PartStatus::Solved(_) | PartStatus::Unimplemented
+        )
+    }
+}
+
+/// Outcome of one day in a runner invocation. Time spent in `setup` counts towards part 1.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DayReport {
+    pub year: u32,
+    pub day: u32,
+    pub part_1: PartReport,
+    pub part_2: PartReport,
+}
+
+impl DayReport {
+    fn failed(year: u32, day: u32, status: PartStatus) -> Self {
+        DayReport {
+            year,
+            day,
+            part_1: PartReport::new(status.clone(), Duration::ZERO),
+            part_2: PartReport::new(status, Duration::ZERO),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.part_1.is_ok() && self.part_2.is_ok()
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        msg.to_string()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        String::from("unknown panic payload")
+    }
+}
+
+fn run_part<F: FnOnce() -> AocResult<String>>(part: F) -> PartStatus {
+    match panic::catch_unwind(AssertUnwindSafe(part)) {
+        Ok(Ok(answer)) => PartStatus::Solved(answer),
+        Ok(Err(e)) if matches!(e.root(), AocError::Unimplemented) => PartStatus::Unimplemented,
+        Ok(Err(e)) => PartStatus::Errored(e.to_string()),
+        Err(payload) => PartStatus::Panicked(panic_message(payload)),
+    }
+}
+
+/// Whether the runner has given up on the day running on this thread. A thread
+/// cannot be killed, so a timed out day keeps a core busy until it returns;
+/// long searches should check this now and then and stop early.
+pub fn is_cancelled() -> bool {
+    WORKER_STATE.with(|state| {
+        state
+            .get()
+            .is_some_and(|state| state.load(Ordering::SeqCst) == ABANDONED)
+    })
+}
+
+/// [`is_cancelled`] as an error, so a day's loop can bail out with `?`.
+pub fn check_cancelled() -> AocResult<()> {
+    if is_cancelled() {
+        return Err(AocError::other("cancelled after timing out"));
+    }
+
+    Ok(())
+}
+
+/// Timed out days that are still running in the background.
+pub fn abandoned_workers() -> usize {
+    ABANDONED_WORKERS.load(Ordering::SeqCst)
+}
+
+/// Runs a day on its own thread so a panic only fails that day and a runaway
+/// solution is abandoned once `timeout` has elapsed. An abandoned day is told
+/// so through [`is_cancelled`], but runs on until it returns.
+pub fn run_isolated(year: u32, mut day: Box<dyn BaseDay>, timeout: Duration) -> DayReport {
+    let day_number = day.get_day_number();
+    let (tx, rx) = mpsc::channel();
+    let state = Arc::new(AtomicU8::new(RUNNING));
+    let worker_state = Arc::clone(&state);
+
+    let worker = thread::Builder::new()
+        .name(format!("{}-day-{}", year, day_number))
+        .stack_size(WORKER_STACK_SIZE)
+        .spawn(move || {
+            WORKER_STATE.with(|cell| {
+                let _ = cell.set(Arc::clone(&worker_state));
+            });
+
+            let started = Instant::now();
+            let status = run_part(|| day.setup().map(|()| String::new()));
+            if matches!(status, PartStatus::Solved(_)) {
+                let status = run_part(|| day.part_1());
+                let _ = tx.send((status, started.elapsed()));
+
+                let started = Instant::now();
+                let status = run_part(|| day.part_2());
+                let _ = tx.send((status, started.elapsed()));
+            } else {
+                let _ = tx.send((status.clone(), started.elapsed()));
+                let _ = tx.send((status, Duration::ZERO));
+            }
+
+            let finished = worker_state.compare_exchange(
+                RUNNING,
+                FINISHED,
+                Ordering::SeqCst,
+                Ordering::SeqCst,
+            );
+            if finished.is_err() {
+                ABANDONED_WORKERS.fetch_sub(1, Ordering::SeqCst);
+            }
+        });
+
+    if let Err(e) = worker {
+        return DayReport::failed(year, day_number, PartStatus::Errored(e.to_string()));
+    }
+
+    let deadline = Instant::now() + timeout;
+    let mut part_started = Instant::now();
+    let mut parts = Vec::with_capacity(2);
+    while parts.len() < 2 {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match rx.recv_timeout(remaining) {
+            Ok((status, duration)) => {
+                parts.push(PartReport::new(status, duration));
+                part_started = Instant::now();
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                // Counted first, so the worker never sees itself abandoned before it is.
+                ABANDONED_WORKERS.fetch_add(1, Ordering::SeqCst);
+                let abandoned =
+                    state.compare_exchange(RUNNING, ABANDONED, Ordering::SeqCst, Ordering::SeqCst);
+                if abandoned.is_err() {
+                    ABANDONED_WORKERS.fetch_sub(1, Ordering::SeqCst);
+                }
+                parts.push(PartReport::new(
+                    PartStatus::TimedOut,
+                    part_started.elapsed(),
+                ));
+                if parts.len() < 2 {
+                    parts.push(PartReport::new(PartStatus::TimedOut, Duration::ZERO));
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                let status = PartStatus::Panicked(String::from("worker exited unexpectedly"));
+                parts.push(PartReport::new(status, part_started.elapsed()));
+            }
+        }
+    }
+
+    let part_2 = parts.pop().unwrap();
+    let part_1 = parts.pop().unwrap();
+    DayReport {
+        year,
+        day: day_number,
+        part_1,
+        part_2,
+    }
+}
+
+fn log_report(report: &DayReport) {
+    for (part, result) in [(1, &report.part_1), (2, &report.part_2)] {
+        if result.is_solved() {
+            info!(
+                "Day {} - part {}: {} ({:.2?})",
+                report.day, part, result.status, result.duration
+            );
+        } else if result.is_ok() {
+            warn!("Day {} - part {}: {}", report.day, part, result.status);
+        } else {
+            error!(
+                "Day {} - part {}: {} ({:.2?})",
+                report.day, part, result.status, result.duration
+            );
+        }
+    }
+}
+
+/// Runs `days` on a pool of `jobs` worker threads, each day still isolated by
+/// [`run_isolated`]. Entries that already carry a report, such as a failed
+/// download, are passed through in place. `on_report` sees the reports in the
+/// order of `days`, each as soon as every earlier day has finished.
+pub fn run_parallel<F: FnMut(&DayReport)>(
+    year: u32,
+    days: Vec<Result<Box<dyn BaseDay>, DayReport>>,
+    jobs: usize,
+    timeout: Duration,
+    mut on_report: F,
+) -> Vec<DayReport> {
+    let mut reports = Vec::with_capacity(days.len());
+    let mut queue = VecDeque::new();
+    for (index, day) in days.into_iter().enumerate() {
+        match day {
+            Ok(day) => {
+                queue.push_back((index, day));
+                reports.push(None);
+            }
+            Err(report) => reports.push(Some(report)),
+        }
+    }
+
+    let workers = jobs.clamp(1, queue.len().max(1));
+    let queue = Mutex::new(queue);
+    let (tx, rx) = mpsc::channel();
+    let mut next = 0;
+
+    thread::scope(|scope| {
+        for _ in 0..workers {
+            let tx = tx.clone();
+            let queue = &queue;
+            scope.spawn(move || {
+                while let Some((index, day)) = queue.lock().unwrap().pop_front() {
+                    if tx.send((index, run_isolated(year, day, timeout))).is_err() {
+                        break;
+                    }
+                }
+            });
+        }
+        drop(tx);
+
+        loop {
+            while let Some(Some(report)) = reports.get(next) {
+                on_report(report);
+                next += 1;
+            }
+            let Ok((index, report)) = rx.recv() else {
+                break;
+            };
+            reports[index] = Some(report);
+        }
+    });
+
+    let abandoned = abandoned_workers();
+    if abandoned > 0 {
+        warn!(
+            "{} timed out day(s) still running in the background, later timings may be skewed",
+            abandoned
+        );
+    }
+
+    reports.into_iter().flatten().collect()
+}
+
+fn show_history(year: u32, day: u32, profile: &str, limit: usize) -> AocResult<()> {
+    let path = runs_db_path();
+    let entries = History::open(&path)?.day(year, day, profile, limit)?;
+    if entries.is_empty() {
+        println!(
+            "No recorded runs of {} day {} ({}) in {}",
+            year,
+            day,
+            profile,
+            path.display()
+        );
+    } else {
+        print!("{}", render_trend(&entries));
+    }
+
+    Ok(())
+}
+
+fn show_status(year: u32, registry: fn() -> Vec<Box<dyn BaseDay>>, profile: &str) -> AocResult<()> {
+    let registered: BTreeSet<u32> = registry().iter().map(|d| d.get_day_number()).collect();
+    let progress = History::open(&runs_db_path())?.progress(year, profile)?;
+    print!(
+        "{}",
+        render_calendar(year, &day_statuses(&registered, &progress))
+    );
+
+    Ok(())
+}
+
+/// Best effort, the generated code compiles either way.
+fn format_year(year: u32) {
+    let formatted = ProcessCommand::new("cargo")
+        .args(["fmt", "-p", &format!("year{}", year)])
+        .current_dir(project_root())
+        .status();
+    if !formatted.is_ok_and(|status| status.success()) {
+        warn!("Unable to run cargo fmt on year{}", year);
+    }
+}
+
+async fn scaffold_day(year: u32, day: u32) -> AocResult<()> {
+    let path = scaffold::new_day(&project_root(), year, day)?;
+    info!("Created {}", path.display());
+    format_year(year);
+
+    match download_input_file(year, day).await {
+        Ok(_) => info!(
+            "Input stored at {}",
+            InputStore::configured()?
+                .location(year, day, DEFAULT_PROFILE)
+                .display()
+        ),
+        Err(e) => warn!("Unable to fetch the input: {}", e),
+    }
+    match scaffold::fetch_example(year, day, &get_data_dir(year, day)).await {
+        Ok(Some(path)) => info!("Example saved to {}", path.display()),
+        Ok(None) => {}
+        Err(e) => warn!("Unable to fetch the example: {}", e),
+    }
+
+    Ok(())
+}
+
+async fn scaffold_year(year: u32) -> AocResult<()> {
+    let dir = scaffold::new_year(&project_root(), year)?;
+    info!("Created {}", dir.display());
+
+    scaffold_day(year, 1).await
+}
+
+fn migrate_year_inputs(year: u32) -> AocResult<()> {
+    let config = get_config()?;
+    let store = InputStore::from_config(&config.inputs)?;
+    if matches!(store, InputStore::Repo) {
+        warn!("Inputs are configured to stay in data/, nothing to migrate");
+        return Ok(());
+    }
+
+    for profile in config.profile_names() {
+        let moved = migrate_inputs(&store, year, profile)?;
+        if !moved.is_empty() {
+            info!(
+                "Moved {} input(s) of {} ({:?})",
+                moved.len(),
+                profile,
+                moved
+            );
+        }
+    }
+
+    Ok(())
+}
+
+async fn show_leaderboard(year: u32, id: Option<u64>, profile: &str) -> AocResult<()> {
+    let config = get_config()?;
+    let id = id.or(config.leaderboard).ok_or_else(|| {
+        AocError::Config(String::from(
+            "no leaderboard id given and none set in config.toml",
+        ))
+    })?;
+
+    let leaderboard = leaderboard::fetch(year, id, config.cookie_for(profile)?).await?;
+    print!("{}", leaderboard::render(&leaderboard, year));
+
+    Ok(())
+}
+
+fn record_run(profile: &str, reports: &[DayReport]) {
+    if reports.is_empty() {
+        return;
+    }
+
+    let recorded = History::open(&runs_db_path())
+        .and_then(|mut history| history.record(&RunInfo::current(profile), reports));
+    if let Err(e) = recorded {
+        warn!("Unable to record the run: {}", e);
+    }
+}
+
+fn select_days(
+    year: u32,
+    registry: fn() -> Vec<Box<dyn BaseDay>>,
+    day: Option<u32>,
+) -> Result<Vec<Box<dyn BaseDay>>, String> {
+    let selected: Vec<Box<dyn BaseDay>> = registry()
+        .into_iter()
+        .filter(|d| day.is_none_or(|n| n == d.get_day_number()))
+        .collect();
+
+    match day {
+        Some(day) if selected.is_empty() => {
+            Err(format!("day {} is not registered for {}", day, year))
+        }
+        _ => Ok(selected),
+    }
+}
+
+/// Compares the solved parts of a report with the answers last recorded for the
+/// profile, returning whether none of them differ.
+fn cross_check(history: &History, profile: &str, report: &DayReport) -> AocResult<bool> {
+    let mut consistent = true;
+
+    for (part, result) in [(1, &report.part_1), (2, &report.part_2)] {
+        let Some(answer) = result.status.answer() else {
+            continue;
+        };
+
+        match history.recorded_answer(report.year, report.day, part, profile)? {
+            Some(recorded) if recorded == answer => {}
+            Some(recorded) => {
+                error!(
+                    "Day {} - part {} [{}]: {:?} differs from the recorded answer {:?}",
+                    report.day, part, profile, answer, recorded
+                );
+                consistent = false;
+            }
+            None => warn!(
+                "Day {} - part {} [{}]: no recorded answer to compare with",
+                report.day, part, profile
+            ),
+        }
+    }
+
+    Ok(consistent)
+}
+
+/// Runs the selected days once per profile, on the inputs already stored for it.
+fn run_all_profiles(
+    year: u32,
+    registry: fn() -> Vec<Box<dyn BaseDay>>,
+    args: &Args,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let config = get_config()?;
+    let store = InputStore::from_config(&config.inputs)?;
+    let history = History::open(&runs_db_path())?;
+    let timeout = Duration::from_secs(args.timeout);
+    let mut failures = 0;
+
+    for profile in config.profile_names() {
+        let days: Vec<Result<Box<dyn BaseDay>, DayReport>> = select_days(year, registry, args.day)?
+            .into_iter()
+            .filter(|day| store.contains(year, day.get_day_number(), profile))
+            .map(|mut day| {
+                let day_number = day.get_day_number();
+                match store.load(year, day_number, profile) {
+                    Ok(source) => {
+                        day.set_input(source);
+                        Ok(day)
+                    }
+                    Err(e) => Err(DayReport::failed(
+                        year,
+                        day_number,
+                        PartStatus::Errored(format!("unable to load input: {}", e)),
+                    )),
+                }
+            })
+            .collect();
+        if days.is_empty() {
+            continue;
+        }
+
+        info!("Profile {}", profile);
+        let reports = run_parallel(year, days, args.worker_count(), timeout, log_report);
+        for report in &reports {
+            if !cross_check(&history, profile, report)? || !report.is_success() {
+                failures += 1;
+            }
+        }
+        record_run(profile, &reports);
+    }
+
+    if failures > 0 {
+        return Err(format!(
+            "{} day(s) failed or disagree with recorded answers",
+            failures
+        )
+        .into());
+    }
+
+    Ok(())
+}
+
+/// Entry point shared by the yearly binaries. `registry` builds fresh instances
+/// of every day, so a day can be run more than once, e.g. for each profile.
+pub async fn run(
+    year: u32,
+    registry: fn() -> Vec<Box<dyn BaseDay>>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let args = Args::parse();
+    init_logger();
+
+    match args.command {
+        Some(Command::History { day, limit }) => {
+            return Ok(show_history(year, day, &args.profile, limit)?);
+        }
+        Some(Command::Leaderboard { id }) => {
+            return Ok(show_leaderboard(year, id, &args.profile).await?);
+        }
+        Some(Command::Status) => return Ok(show_status(year, registry, &args.profile)?),
+        Some(Command::NewDay { day, year: target }) => {
+            return Ok(scaffold_day(target.unwrap_or(year), day).await?);
+        }
+        Some(Command::NewYear { year }) => return Ok(scaffold_year(year).await?),
+        Some(Command::MigrateInputs) => return Ok(migrate_year_inputs(year)?),
+        None => {}
+    }
+    if args.all_profiles {
+        return run_all_profiles(year, registry, &args);
+    }
+
+    let timeout = Duration::from_secs(args.timeout);
+    let jobs = args.worker_count();
+    let profile = args.profile.as_str();
+    let selected = select_days(year, registry, args.day)?;
+
+    // A custom input is only ever given for a single day, and its answers say
+    // nothing about our own input, so the run is not recorded either.
+    let custom_input = args.input_source()?;
+    let record = custom_input.is_none();
+
+    let days: Vec<Result<Box<dyn BaseDay>, DayReport>> = match custom_input {
+        Some(source) => selected
+            .into_iter()
+            .map(|mut day| {
+                day.set_input(source.clone());
+                Ok(day)
+            })
+            .collect(),
+        None => {
+            stream::iter(selected)
+                .map(|mut day| async move {
+                    let day_number = day.get_day_number();
+                    match download_profile_input_file(year, day_number, profile).await {
+                        Ok(source) => {
+                            day.set_input(source);
+                            Ok(day)
+                        }
+                        Err(e) => Err(DayReport::failed(
+                            year,
+                            day_number,
+                            PartStatus::Errored(format!("unable to download input: {}", e)),
+                        )),
+                    }
+                })
+                .buffered(jobs)
+                .collect()
+                .await
+        }
+    };
+
+    let reports = run_parallel(year, days, jobs, timeout, log_report);
+    if record {
+        record_run(profile, &reports);
+    }
+    if let Some(format) = args.format {
+        print!("{}", render(&reports, format));
+    }
+
+    let failures = reports.iter().filter(|r| !r.is_success()).count();
+    if failures > 0 {
+        return Err(format!("{} day(s) failed", failures).into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::Input;
+    use std::sync::atomic::AtomicBool;
+
+    static SPIN_STOPPED: AtomicBool = AtomicBool::new(false);
+
+    enum Behaviour {
+        Solve,
+        Error,
+        Panic,
+        Hang,
+        /// Busy until the runner cancels it.
+        Spin,
+        Unimplemented,
+    }
+
+    struct FakeDay {
+        day: u32,
+        part_1: Behaviour,
+        part_2: Behaviour,
+        input: Input,
+    }
+
+    fn act(behaviour: &Behaviour) -> AocResult<String> {
+        match behaviour {
+            Behaviour::Solve => Ok(String::from("42")),
+            Behaviour::Error => Err(AocError::NoSolution),
+            Behaviour::Panic => panic!("boom"),
+            Behaviour::Unimplemented => Err(AocError::Unimplemented),
+            Behaviour::Hang => {
+                thread::sleep(Duration::from_secs(5));
+                Ok(String::new())
+            }
+            Behaviour::Spin => loop {
+                if let Err(e) = check_cancelled() {
+                    SPIN_STOPPED.store(true, Ordering::SeqCst);
+                    return Err(e);
+                }
+                thread::sleep(Duration::from_millis(1));
+            },
+        }
+    }
+
+    impl BaseDay for FakeDay {
+        fn get_day_number(&self) -> u32 {
+            self.day
+        }
+
+        fn part_1(&mut self) -> AocResult<String> {
+            act(&self.part_1)
+        }
+
+        fn part_2(&mut self) -> AocResult<String> {
+            act(&self.part_2)
+        }
+
+        fn input(&self) -> &Input {
+            &self.input
+        }
+
+        fn input_mut(&mut self) -> &mut Input {
+            &mut self.input
+        }
+    }
+
+    fn run(part_1: Behaviour, part_2: Behaviour) -> DayReport {
+        let day = FakeDay {
+            day: 1,
+            part_1,
+            part_2,
+            input: PathBuf::new().into(),
+        };
+        run_isolated(2024, Box::new(day), Duration::from_secs(1))
+    }
+
+    #[test]
+    fn solved_test() {
+        let report = run(Behaviour::Solve, Behaviour::Solve);
+        assert!(report.is_success());
+        assert_eq!(report.part_2.status, PartStatus::Solved(String::from("42")));
+    }
+
+    #[test]
+    fn panic_in_part_1_still_runs_part_2_test() {
+        let report = run(Behaviour::Panic, Behaviour::Error);
+        assert_eq!(
+            report.part_1.status,
+            PartStatus::Panicked(String::from("boom"))
+        );
+        assert_eq!(
+            report.part_2.status,
+            PartStatus::Errored(String::from("no solution found"))
+        );
+    }
+
+    #[test]
+    fn unimplemented_is_not_a_failure_test() {
+        let report = run(Behaviour::Solve, Behaviour::Unimplemented);
+        assert_eq!(report.part_2.status, PartStatus::Unimplemented);
+        assert!(report.is_success());
+    }
+
+    #[test]
+    fn timeout_test() {
+        let report = run(Behaviour::Solve, Behaviour::Hang);
+        assert!(report.part_1.is_solved());
+        assert_eq!(report.part_2.status, PartStatus::TimedOut);
+
+        let report = run(Behaviour::Hang, Behaviour::Solve);
+        assert_eq!(report.part_1.status, PartStatus::TimedOut);
+        assert_eq!(report.part_2.status, PartStatus::TimedOut);
+    }
+
+    #[test]
+    fn cancel_test() {
+        assert!(!is_cancelled());
+
+        let report = run(Behaviour::Spin, Behaviour::Solve);
+        assert_eq!(report.part_1.status, PartStatus::TimedOut);
+
+        let deadline = Instant::now() + Duration::from_secs(2);
+        while !SPIN_STOPPED.load(Ordering::SeqCst) && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+        assert!(SPIN_STOPPED.load(Ordering::SeqCst));
+    }
+
+    #[test]
+    fn parallel_reports_in_day_order_test() {
+        let slow = FakeDay {
+            day: 1,
+            part_1: Behaviour::Hang,
+            part_2: Behaviour::Solve,
+            input: PathBuf::new().into(),
+        };
+        let fast = FakeDay {
+            day: 2,
+            part_1: Behaviour::Solve,
+            part_2: Behaviour::Solve,
+            input: PathBuf::new().into(),
+        };
+        let download_failed = DayReport::failed(2024, 3, PartStatus::TimedOut);
+        let days: Vec<Result<Box<dyn BaseDay>, DayReport>> =
+            vec![Ok(Box::new(slow)), Ok(Box::new(fast)), Err(download_failed)];
+
+        let mut seen = Vec::new();
+        let reports = run_parallel(2024, days, 2, Duration::from_millis(300), |r| {
+            seen.push(r.day)
+        });
+
+        assert_eq!(seen, vec![1, 2, 3]);
+        assert_eq!(reports.len(), 3);
+        assert!(!reports[0].is_success());
+        assert!(reports[1].is_success());
+    }
+
+    #[test]
+    fn cross_check_test() -> AocResult<()> {
+        let mut history = History::open(std::path::Path::new(":memory:"))?;
+        let info = RunInfo {
+            revision: String::from("abc1234"),
+            machine: String::from("test"),
+            profile: String::from("alice"),
+        };
+        let recorded = run(Behaviour::Solve, Behaviour::Error);
+        history.record(&info, &[recorded])?;
+
+        assert!(cross_check(
+            &history,
+            "alice",
+            &run(Behaviour::Solve, Behaviour::Solve)
+        )?);
+        assert!(cross_check(
+            &history,
+            "bob",
+            &run(Behaviour::Solve, Behaviour::Solve)
+        )?);
+
+        let mut changed = run(Behaviour::Solve, Behaviour::Solve);
+        changed.part_1.status = PartStatus::Solved(String::from("43"));
+        assert!(!cross_check(&history, "alice", &changed)?);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+use crate::config::{DEFAULT_PROFILE, get_config};
+use crate::error::{AocError, AocResult, Context};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DAY_TEMPLATE: &str = include_str!("../templates/day.rs.template");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.template");
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.template");
+const BUILD_TEMPLATE: &str = include_str!("../templates/build.rs.template");
+
+/// Written for every new day, so the example test of a year always has a file
+/// to pick up. The example is skipped until at least one answer is filled in.
+const EXPECTED_SKELETON: &str = "# part_1 = \n# part_2 = \n";
+
+fn fill(template: &str, year: u32, day: Option<u32>) -> String {
+    let filled = template.replace("{{year}}", &year.to_string());
+    match day {
+        Some(day) => filled.replace("{{day}}", &day.to_string()),
+        None => filled,
+    }
+}
+
+fn write_new(path: &Path, content: &str) -> AocResult<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+    }
+    fs::write(path, content).with_context(|| format!("writing {}", path.display()))?;
+
+    Ok(())
+}
+
+fn year_dir(root: &Path, year: u32) -> PathBuf {
+    root.join(format!("year{}", year))
+}
+
+/// Adds `mod day_<n>;`, its `use` and its entry in `days()` to a year's `main.rs`.
+pub fn register_day(main_rs: &str, day: u32) -> AocResult<String> {
+    let missing = |what: &str| AocError::parse(format!("main.rs has no {}", what));
+    let mut lines: Vec<String> = main_rs.lines().map(String::from).collect();
+
+    let import = match lines
+        .iter()
+        .rposition(|line| line.starts_with("use crate::"))
+    {
+        Some(last_import) => last_import + 1,
+        None => lines
+            .iter()
+            .position(|line| line == "use common::base_day::BaseDay;")
+            .ok_or_else(|| missing("`use common::base_day::BaseDay;`"))?,
+    };
+    lines.insert(import, format!("use crate::day_{}::Day{};", day, day));
+
+    let is_mod = |line: &String| line.starts_with("mod ") && line.ends_with(';');
+    match lines.iter().rposition(is_mod) {
+        Some(last_mod) => lines.insert(last_mod + 1, format!("mod day_{};", day)),
+        None => {
+            let after_uses = lines
+                .iter()
+                .rposition(|line| line.starts_with("use "))
+                .ok_or_else(|| missing("`use` lines"))?;
+            lines.insert(after_uses + 1, String::new());
+            lines.insert(after_uses + 2, format!("mod day_{};", day));
+        }
+    }
+
+    let days_fn = lines
+        .iter()
+        .position(|line| line.starts_with("fn days()"))
+        .ok_or_else(|| missing("`fn days()`"))?;
+    let entry = format!("        Box::new(Day{}::new()),", day);
+    let list = lines[days_fn + 1].trim().to_string();
+    if list == "vec![" {
+        let close = lines[days_fn..]
+            .iter()
+            .position(|line| line == "    ]")
+            .ok_or_else(|| missing("end of the `days()` list"))?;
+        lines.insert(days_fn + close, entry);
+    } else if let Some(inner) = list.strip_prefix("vec![").and_then(|l| l.strip_suffix(']')) {
+        // rustfmt keeps short lists on one line.
+        let mut entries: Vec<String> = inner
+            .split(',')
+            .map(str::trim)
+            .filter(|e| !e.is_empty())
+            .map(|e| format!("        {},", e))
+            .collect();
+        entries.push(entry);
+        entries.push(String::from("    ]"));
+        lines[days_fn + 1] = String::from("    vec![");
+        lines.splice(days_fn + 2..days_fn + 2, entries);
+    } else {
+        return Err(missing("`vec!` in `days()`"));
+    }
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Adds a crate to the workspace `members` list, keeping it sorted.
+pub fn add_workspace_member(cargo_toml: &str, member: &str) -> AocResult<String> {
+    let missing = || AocError::parse("Cargo.toml has no `members = [` list");
+    let mut lines: Vec<String> = cargo_toml.lines().map(String::from).collect();
+
+    let start = lines
+        .iter()
+        .position(|line| line.trim() == "members = [")
+        .ok_or_else(missing)?;
+    let end = start
+        + lines[start..]
+            .iter()
+            .position(|line| line.trim() == "]")
+            .ok_or_else(missing)?;
+
+    let entry = format!("    \"{}\",", member);
+    if lines[start + 1..end].contains(&entry) {
+        return Err(AocError::other(format!("{} is already a member", member)));
+    }
+    let position = lines[start + 1..end]
+        .iter()
+        .position(|line| *line > entry)
+        .map_or(end, |offset| start + 1 + offset);
+    lines.insert(position, entry);
+
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Creates `src/day_<n>.rs` in an existing year crate, registers it and adds an
+/// empty expectation for its first example, returning the new file.
+pub fn new_day(root: &Path, year: u32, day: u32) -> AocResult<PathBuf> {
+    if !(1..=25).contains(&day) {
+        return Err(AocError::other(format!("day {} is not in 1..=25", day)));
+    }
+
+    let src = year_dir(root, year).join("src");
+    let main_rs = src.join("main.rs");
+    let main = fs::read_to_string(&main_rs).with_context(|| {
+        format!(
+            "reading {}, create the year with new-year first",
+            main_rs.display()
+        )
+    })?;
+
+    let day_rs = src.join(format!("day_{}.rs", day));
+    let as_bin = src.join(format!("bin/day_{}.rs", day));
+    if day_rs.exists() || as_bin.exists() {
+        return Err(AocError::other(format!(
+            "{} day {} already exists",
+            year, day
+        )));
+    }
+
+    let main = register_day(&main, day)?;
+    write_new(&day_rs, &fill(DAY_TEMPLATE, year, Some(day)))?;
+    fs::write(&main_rs, main).with_context(|| format!("writing {}", main_rs.display()))?;
+
+    let expected = root.join(format!("data/{}/day_{}/example_1.expected.toml", year, day));
+    if !expected.exists() {
+        write_new(&expected, EXPECTED_SKELETON)?;
+    }
+
+    Ok(day_rs)
+}
+
+/// Creates the `year<year>` crate and adds it to the workspace.
+pub fn new_year(root: &Path, year: u32) -> AocResult<PathBuf> {
+    let dir = year_dir(root, year);
+    if dir.exists() {
+        return Err(AocError::other(format!("{} already exists", dir.display())));
+    }
+
+    let workspace = root.join("Cargo.toml");
+    let members = fs::read_to_string(&workspace)
+        .with_context(|| format!("reading {}", workspace.display()))?;
+    let members = add_workspace_member(&members, &format!("year{}", year))?;
+
+    write_new(&dir.join("Cargo.toml"), &fill(CARGO_TEMPLATE, year, None))?;
+    write_new(&dir.join("build.rs"), &fill(BUILD_TEMPLATE, year, None))?;
+    write_new(&dir.join("src/main.rs"), &fill(MAIN_TEMPLATE, year, None))?;
+    fs::write(&workspace, members).with_context(|| format!("writing {}", workspace.display()))?;
+
+    Ok(dir)
+}
+
+fn unescape(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        rest = rest[start..]
+            .find('>')
+            .map_or("", |end| &rest[start + end + 1..]);
+    }
+    text.push_str(rest);
+
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// The `<pre><code>` blocks of a puzzle page, which hold its examples.
+pub fn example_blocks(html: &str) -> Vec<String> {
+    html.split("<pre><code>")
+        .skip(1)
+        .filter_map(|block| block.split_once("</code></pre>"))
+        .map(|(code, _)| unescape(code))
+        .collect()
+}
+
+/// Saves the first example of a puzzle as `example_1.txt`, unless there
+/// already is one.
+pub async fn fetch_example(year: u32, day: u32, data_dir: &Path) -> AocResult<Option<PathBuf>> {
+    let path = data_dir.join("example_1.txt");
+    if path.exists() {
+        return Ok(None);
+    }
+
+    let config = get_config()?;
+    let response = reqwest::Client::new()
+        .get(format!("https://adventofcode.com/{}/day/{}", year, day))
+        .header("Cookie", config.cookie_for(DEFAULT_PROFILE)?)
+        .send()
+        .await?;
+    if !response.status().is_success() {
+        return Err(AocError::Download(format!(
+            "{} for the puzzle of {} day {}",
+            response.status(),
+            year,
+            day
+        )));
+    }
+
+    let Some(example) = example_blocks(&response.text().await?).into_iter().next() else {
+        return Ok(None);
+    };
+    write_new(&path, &example)?;
+
+    Ok(Some(path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::project_root;
+
+    #[test]
+    fn register_day_test() -> AocResult<()> {
+        let main = fill(MAIN_TEMPLATE, 2025, None);
+        let main = register_day(&main, 1)?.replace(
+            "    vec![\n        Box::new(Day1::new()),\n    ]",
+            "    vec![Box::new(Day1::new())]",
+        );
+        let main = register_day(&main, 2)?;
+
+        assert!(main.starts_with(
+            "use crate::day_1::Day1;\nuse crate::day_2::Day2;\nuse common::base_day::BaseDay;\n\nmod day_1;\nmod day_2;\n"
+        ));
+        assert!(main.contains(
+            "    vec![\n        Box::new(Day1::new()),\n        Box::new(Day2::new()),\n    ]\n"
+        ));
+
+        let existing = fs::read_to_string(project_root().join("year2023/src/main.rs"))?;
+        assert!(register_day(&existing, 26).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn workspace_member_test() -> AocResult<()> {
+        let workspace = fs::read_to_string(project_root().join("Cargo.toml"))?;
+        let updated = add_workspace_member(&workspace, "year2025")?;
+        assert!(updated.contains("    \"year2024\",\n    \"year2025\",\n]"));
+
+        let updated = add_workspace_member(&updated, "year2015")?;
+        assert!(updated.contains("    \"common\",\n    \"year2015\",\n    \"year2023\","));
+        assert!(add_workspace_member(&updated, "year2015").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn example_blocks_test() {
+        let html = "<p>For example:</p>\n<pre><code>1 &lt; 2\n<em>x</em> &amp; y\n</code></pre>\
+                    <p>Then</p><pre><code>second\n</code></pre>";
+
+        assert_eq!(example_blocks(html), vec!["1 < 2\nx & y\n", "second\n"]);
+    }
+}
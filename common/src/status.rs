@@ -0,0 +1,164 @@
+use crate::history::PartProgress;
+use crate::leaderboard::days_from_civil;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+/// How far a day of the calendar has got.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DayStatus {
+    /// No solution is registered for the day.
+    Missing,
+    /// Registered, but still a stub according to its last run.
+    Unimplemented,
+    Unsolved,
+    PartOne,
+    Both,
+}
+
+impl DayStatus {
+    fn symbol(self) -> &'static str {
+        match self {
+            DayStatus::Missing => "  ",
+            DayStatus::Unimplemented => "--",
+            DayStatus::Unsolved => "..",
+            DayStatus::PartOne => "* ",
+            DayStatus::Both => "**",
+        }
+    }
+
+    pub fn stars(self) -> u32 {
+        match self {
+            DayStatus::PartOne => 1,
+            DayStatus::Both => 2,
+            _ => 0,
+        }
+    }
+}
+
+/// Combines the registered days with what their recorded runs achieved. A part
+/// counts as done once any run solved it. Day 25 has no second puzzle, its star
+/// comes with the other 49.
+pub fn day_statuses(
+    registered: &BTreeSet<u32>,
+    progress: &[PartProgress],
+) -> BTreeMap<u32, DayStatus> {
+    let solved = |day, part| {
+        progress
+            .iter()
+            .any(|p| p.day == day && p.part == part && p.solved)
+    };
+    let stub = |day| {
+        progress
+            .iter()
+            .any(|p| p.day == day && p.latest_status == "unimplemented")
+    };
+
+    let mut statuses: BTreeMap<u32, DayStatus> = (1..=25)
+        .map(|day| {
+            let status = if !registered.contains(&day) {
+                DayStatus::Missing
+            } else if solved(day, 1) && solved(day, 2) {
+                DayStatus::Both
+            } else if solved(day, 1) {
+                DayStatus::PartOne
+            } else if stub(day) {
+                DayStatus::Unimplemented
+            } else {
+                DayStatus::Unsolved
+            };
+            (day, status)
+        })
+        .collect();
+
+    let others_done = (1..25).all(|day| statuses[&day] == DayStatus::Both);
+    if others_done && statuses[&25] == DayStatus::PartOne {
+        statuses.insert(25, DayStatus::Both);
+    }
+
+    statuses
+}
+
+/// The days of December laid out by weekday, like a wall calendar.
+pub fn render_calendar(year: u32, statuses: &BTreeMap<u32, DayStatus>) -> String {
+    let stars: u32 = statuses.values().map(|status| status.stars()).sum();
+    let mut output = String::new();
+    let _ = writeln!(output, "{}: {} stars", year, stars);
+    let _ = writeln!(output, "  Mon  Tue  Wed  Thu  Fri  Sat  Sun");
+
+    // 1970-01-01 was a Thursday.
+    let first_weekday = (days_from_civil(year as i64, 12, 1) + 3).rem_euclid(7) as usize;
+    output.push_str(&" ".repeat(5 * first_weekday));
+    for (&day, status) in statuses {
+        let _ = write!(output, "{:>3}{}", day, status.symbol());
+        if (first_weekday + day as usize).is_multiple_of(7) {
+            output.push('\n');
+        }
+    }
+    if !output.ends_with('\n') {
+        output.push('\n');
+    }
+    output.push_str("\n** both parts  * part 1  .. unsolved  -- not implemented\n");
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn progress(day: u32, part: u32, solved: bool, latest_status: &str) -> PartProgress {
+        PartProgress {
+            day,
+            part,
+            solved,
+            latest_status: latest_status.to_string(),
+        }
+    }
+
+    #[test]
+    fn statuses_test() {
+        let registered = BTreeSet::from([1, 2, 3, 4]);
+        let statuses = day_statuses(
+            &registered,
+            &[
+                progress(1, 1, true, "solved"),
+                progress(1, 2, true, "errored"),
+                progress(2, 1, true, "solved"),
+                progress(2, 2, false, "timed_out"),
+                progress(3, 1, false, "unimplemented"),
+                progress(3, 2, false, "unimplemented"),
+            ],
+        );
+
+        assert_eq!(statuses[&1], DayStatus::Both);
+        assert_eq!(statuses[&2], DayStatus::PartOne);
+        assert_eq!(statuses[&3], DayStatus::Unimplemented);
+        assert_eq!(statuses[&4], DayStatus::Unsolved);
+        assert_eq!(statuses[&5], DayStatus::Missing);
+    }
+
+    #[test]
+    fn free_last_star_test() {
+        let registered: BTreeSet<u32> = (1..=25).collect();
+        let mut parts: Vec<PartProgress> = (1..=25)
+            .map(|day| progress(day, 1, true, "solved"))
+            .collect();
+        parts.extend((1..25).map(|day| progress(day, 2, true, "solved")));
+
+        let statuses = day_statuses(&registered, &parts);
+        assert_eq!(statuses[&25], DayStatus::Both);
+        assert!(render_calendar(2024, &statuses).starts_with("2024: 50 stars\n"));
+    }
+
+    #[test]
+    fn calendar_test() {
+        let statuses = day_statuses(&BTreeSet::from([1]), &[progress(1, 1, true, "solved")]);
+        let calendar = render_calendar(2023, &statuses);
+        let lines: Vec<&str> = calendar.lines().collect();
+
+        // December 2023 started on a Friday.
+        assert_eq!(lines[2], format!("{}  1*   2    3  ", " ".repeat(20)));
+        assert!(lines[3].starts_with("  4    5"));
+        assert!(calendar.starts_with("2023: 1 stars\n"));
+    }
+}
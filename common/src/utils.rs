@@ -5,6 +5,7 @@ use itertools::Itertools;
 use pretty_bytes::converter::convert;
 use std::collections::VecDeque;
 use std::collections::{HashMap, HashSet};
+use std::fmt::{Debug, Display};
 use std::hash::Hash;
 use sysinfo::{Pid, System};
 
@@ -12,15 +13,10 @@ use sysinfo::{Pid, System};
 pub const DIFFS: [(i32, i32); 4] = [(0, 1), (0, -1), (1, 0), (-1, 0)];
 
 pub fn get_neighbours(grid: &Grid<char>, current_position: Point) -> Vec<Point> {
-    let mut result = vec![];
-    for diff in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
-        let neighbour_position = current_position.add(diff.0, diff.1);
-        if grid.get(neighbour_position).is_some_and(|n| *n == '.') {
-            result.push(neighbour_position);
-        }
-    }
-
-    result
+    grid.neighbours(current_position)
+        .into_iter()
+        .filter(|neighbour| grid.get(*neighbour).is_some_and(|n| *n == '.'))
+        .collect()
 }
 
 pub fn add_corners(grid: &Grid<char>, graph: &mut Graph<Point>, value_to_add: char) {
@@ -229,6 +225,40 @@ pub fn bfs_distances<T: PartialEq + Eq + Copy>(
     dist
 }
 
+/// Steps from `start` to every reachable cell, moving only onto cells that are
+/// `passable`. Follows the grid's edges round in wrapping mode.
+pub fn grid_bfs_distances<T>(
+    grid: &Grid<T>,
+    start: Point,
+    passable: impl Fn(&T) -> bool,
+) -> HashMap<Point, i32>
+where
+    T: PartialEq + Debug + Eq + Hash + Clone + Display,
+{
+    let mut dist = HashMap::new();
+    let mut q = VecDeque::new();
+
+    let Some(start) = grid.locate(start) else {
+        return dist;
+    };
+    dist.insert(start, 0);
+    q.push_back(start);
+
+    while let Some(p) = q.pop_front() {
+        let d = dist[&p];
+        for np in grid.neighbours(p) {
+            if dist.contains_key(&np) || !grid.get(np).is_some_and(&passable) {
+                continue;
+            }
+
+            dist.insert(np, d + 1);
+            q.push_back(np);
+        }
+    }
+
+    dist
+}
+
 pub fn init_logger() {
     let env = Env::default()
         .filter_or("MY_LOG_LEVEL", "info")
@@ -238,7 +268,7 @@ pub fn init_logger() {
 }
 
 pub fn manhattan_distance(a: (i32, i32), b: (i32, i32)) -> i32 {
-    (a.0.abs_diff(b.0) + a.1.abs_diff(b.1)) as i32
+    Point::from(a).manhattan_distance(b.into())
 }
 
 pub fn bron_kerbosch<T>(
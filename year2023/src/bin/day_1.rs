@@ -1,9 +1,9 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::utils::init_logger;
 use log::info;
 use std::error::Error;
-use std::path::PathBuf;
 
 const DIGITS: [&str; 20] = [
     "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "0", "1", "2",
@@ -12,7 +12,7 @@ const DIGITS: [&str; 20] = [
 
 pub struct Day1 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Default for Day1 {
@@ -25,7 +25,7 @@ impl Day1 {
     pub fn new() -> Day1 {
         Day1 {
             day_number: 1,
-            file_path: get_input_path(2023, 1),
+            input: get_input_path(2023, 1).into(),
         }
     }
 }
@@ -35,10 +35,10 @@ impl BaseDay for Day1 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let mut result = 0;
 
-        for line in self.read_file_into_vec() {
+        for line in self.read_file_into_vec()? {
             let mut first_digit = None;
             let mut last_digit = None;
 
@@ -58,10 +58,10 @@ impl BaseDay for Day1 {
         Ok(result.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         let mut result = 0;
 
-        for line in self.read_file_into_vec() {
+        for line in self.read_file_into_vec()? {
             let mut first_digit = 0;
             let mut first_position = usize::MAX;
 
@@ -100,8 +100,12 @@ impl BaseDay for Day1 {
         Ok(result.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -110,7 +114,7 @@ impl BaseDay for Day1 {
 async fn main() -> Result<(), Box<dyn Error>> {
     let mut day = Day1::new();
     init_logger();
-    common::file::download_input_file(2023, day.day_number).await?;
+    day.set_input(common::file::download_input_file(2023, day.day_number).await?);
 
     let result = day.run_day()?;
     info!("Day {} - part 1: {:?}", result.day, result.part_1);
@@ -118,40 +122,3 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use common::file::get_data_dir;
-    use common::test_utils;
-
-    #[test]
-    fn test_part_1() -> Result<(), Box<dyn Error>> {
-        test_utils::init_logger();
-
-        let mut day = Day1::new();
-        day.file_path = get_data_dir(2023, 1).join("example_1.txt");
-
-        let expected = String::from("142");
-
-        let result = day.part_1()?;
-        assert_eq!(expected, result);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_part_2() -> Result<(), Box<dyn Error>> {
-        test_utils::init_logger();
-
-        let mut day = Day1::new();
-        day.file_path = get_data_dir(2023, 1).join("example_2.txt");
-
-        let expected = String::from("281");
-
-        let result = day.part_2()?;
-        assert_eq!(expected, result);
-
-        Ok(())
-    }
-}
@@ -1,13 +1,12 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::{AocError, AocResult};
+use common::file::{Input, get_input_path};
 use common::utils::init_logger;
 use log::info;
-use std::error::Error;
-use std::path::PathBuf;
 
 pub struct Day13 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Default for Day13 {
@@ -20,7 +19,7 @@ impl Day13 {
     pub fn new() -> Day13 {
         Day13 {
             day_number: 13,
-            file_path: get_input_path(2023, 13),
+            input: get_input_path(2023, 13).into(),
         }
     }
 }
@@ -30,16 +29,20 @@ impl BaseDay for Day13 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
-        Ok(String::new())
+    fn part_1(&mut self) -> AocResult<String> {
+        Err(AocError::Unimplemented)
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
-        Ok(String::new())
+    fn part_2(&mut self) -> AocResult<String> {
+        Err(AocError::Unimplemented)
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -48,7 +51,7 @@ impl BaseDay for Day13 {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut day = Day13::new();
     init_logger();
-    common::file::download_input_file(2023, day.day_number).await?;
+    day.set_input(common::file::download_input_file(2023, day.day_number).await?);
 
     let result = day.run_day()?;
     info!("Day {} - part 1: {:?}", result.day, result.part_1);
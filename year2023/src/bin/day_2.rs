@@ -1,9 +1,8 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::{AocError, AocResult};
+use common::file::{Input, get_input_path};
 use common::utils::init_logger;
 use log::{info, warn};
-use std::error::Error;
-use std::path::PathBuf;
 
 const MAX_RED: u32 = 12;
 const MAX_GREEN: u32 = 13;
@@ -42,7 +41,7 @@ impl Draw {
 
 pub struct Day2 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Default for Day2 {
@@ -55,23 +54,25 @@ impl Day2 {
     pub fn new() -> Day2 {
         Day2 {
             day_number: 2,
-            file_path: get_input_path(2023, 2),
+            input: get_input_path(2023, 2).into(),
         }
     }
 
-    fn process_game_combination(&self, combination: &str) -> Result<Draw, Box<dyn Error>> {
+    fn process_game_combination(&self, combination: &str) -> AocResult<Draw> {
         let mut red_count = 0;
         let mut green_count = 0;
         let mut blue_count = 0;
 
         for item in combination.split(", ") {
-            let (count_str, color) = item.split_once(" ").unwrap();
+            let (count_str, color) = item
+                .split_once(" ")
+                .ok_or_else(|| AocError::parse(format!("Invalid draw '{}'", item)))?;
             let count = count_str.parse::<u32>()?;
             match color {
                 "red" => red_count += count,
                 "green" => green_count += count,
                 "blue" => blue_count += count,
-                other => return Err(format!("Unexpected color '{}'", other).into()),
+                other => return Err(AocError::parse(format!("Unexpected color '{}'", other))),
             }
         }
 
@@ -82,15 +83,15 @@ impl Day2 {
         })
     }
 
-    fn parse_game_line(&self, line: &str) -> Result<Game, Box<dyn Error>> {
+    fn parse_game_line(&self, line: &str) -> AocResult<Game> {
         let Some((game_info, game_data)) = line.split_once(": ") else {
             warn!("Line '{}' is not valid, skipping.", line);
-            return Err("Invalid line format".into());
+            return Err(AocError::parse("Invalid line format"));
         };
         let game_id = game_info
             .split_whitespace()
             .nth(1)
-            .ok_or("Missing game id")?
+            .ok_or_else(|| AocError::parse("Missing game id"))?
             .parse()?;
 
         let game = Game {
@@ -98,7 +99,7 @@ impl Day2 {
             draws: game_data
                 .split("; ")
                 .map(|combination| self.process_game_combination(combination))
-                .collect::<Result<Vec<Draw>, Box<dyn Error>>>()?,
+                .collect::<AocResult<Vec<Draw>>>()?,
         };
 
         Ok(game)
@@ -110,9 +111,9 @@ impl BaseDay for Day2 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         Ok(self
-            .read_file_into_vec()
+            .input_lines()?
             .iter()
             .filter_map(|line| self.parse_game_line(line).ok())
             .filter(|game| game.is_valid())
@@ -121,9 +122,9 @@ impl BaseDay for Day2 {
             .to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         Ok(self
-            .read_file_into_vec()
+            .input_lines()?
             .iter()
             .filter_map(|line| self.parse_game_line(line).ok())
             .map(|game| game.power())
@@ -131,8 +132,12 @@ impl BaseDay for Day2 {
             .to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -142,7 +147,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut day = Day2::new();
     init_logger();
 
-    common::file::download_input_file(2023, day.day_number).await?;
+    day.set_input(common::file::download_input_file(2023, day.day_number).await?);
 
     let result = day.run_day()?;
     info!("Day {} - part 1: {:?}", result.day, result.part_1);
@@ -155,35 +160,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 mod tests {
     use super::*;
 
-    use common::file::get_data_dir;
     use rstest::rstest;
 
-    #[test]
-    fn test_part_1() -> Result<(), Box<dyn Error>> {
-        let expected = String::from("8");
-
-        let mut day = Day2::new();
-        day.file_path = get_data_dir(2023, 2).join("example_1.txt");
-
-        let result = day.part_1()?;
-        assert_eq!(result, expected);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_part_2() -> Result<(), Box<dyn Error>> {
-        let expected = String::from("2286");
-
-        let mut day = Day2::new();
-        day.file_path = get_data_dir(2023, 2).join("example_1.txt");
-
-        let result = day.part_2()?;
-        assert_eq!(result, expected);
-
-        Ok(())
-    }
-
     #[rstest]
     #[case((10, 10, 10), true)]
     #[case((40, 10, 10), false)]
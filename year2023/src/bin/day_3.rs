@@ -1,11 +1,10 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::grid::{Grid, Point};
 use common::utils::init_logger;
 use log::info;
 use std::collections::HashSet;
-use std::error::Error;
-use std::path::PathBuf;
 
 #[derive(Debug)]
 struct PartNumber {
@@ -28,7 +27,7 @@ impl PartNumber {
 
 pub struct Day3 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
     grid: Grid<char>,
     part_numbers: Vec<PartNumber>,
 }
@@ -43,7 +42,7 @@ impl Day3 {
     pub fn new() -> Day3 {
         Day3 {
             day_number: 3,
-            file_path: get_input_path(2023, 3),
+            input: get_input_path(2023, 3).into(),
             grid: Grid::new(),
             part_numbers: Vec::new(),
         }
@@ -128,7 +127,7 @@ impl BaseDay for Day3 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let parts = self
             .grid
             .cache()
@@ -153,7 +152,7 @@ impl BaseDay for Day3 {
         Ok(parts_sum.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         Ok(self
             .grid
             .cache()
@@ -165,15 +164,21 @@ impl BaseDay for Day3 {
             .to_string())
     }
 
-    fn setup(&mut self) {
-        let input = self.read_file_into_vec_of_vec();
+    fn setup(&mut self) -> AocResult<()> {
+        let input = self.read_file_into_vec_of_vec()?;
         let grid = Grid::from_vector(&input);
         self.part_numbers = self.part_numbers(&grid);
         self.grid = grid;
+
+        Ok(())
+    }
+
+    fn input(&self) -> &Input {
+        &self.input
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -182,7 +187,7 @@ impl BaseDay for Day3 {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut day = Day3::new();
     init_logger();
-    common::file::download_input_file(2023, day.day_number).await?;
+    day.set_input(common::file::download_input_file(2023, day.day_number).await?);
 
     let result = day.run_day()?;
     info!("Day {} - part 1: {:?}", result.day, result.part_1);
@@ -190,34 +195,3 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     Ok(())
 }
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    use common::file::get_data_dir;
-    use rstest::rstest;
-
-    #[rstest]
-    fn test_part_1() -> Result<(), Box<dyn Error>> {
-        let mut day = Day3::new();
-        day.file_path = get_data_dir(2023, 3).join("example_1.txt");
-        day.setup();
-        let expected = String::from("4361");
-
-        assert_eq!(day.part_1()?, expected);
-
-        Ok(())
-    }
-
-    #[rstest]
-    fn test_part_2() -> Result<(), Box<dyn Error>> {
-        let mut day = Day3::new();
-        day.file_path = get_data_dir(2023, 3).join("example_1.txt");
-        day.setup();
-        let expected = String::from("467835");
-
-        assert_eq!(day.part_2()?, expected);
-
-        Ok(())
-    }
-}
@@ -1,12 +1,11 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::time_it;
 use common::utils::init_logger;
 use log::info;
 use std::collections::{HashMap, HashSet};
 use std::error::Error;
-use std::fs;
-use std::path::PathBuf;
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 struct Card<'input> {
@@ -29,7 +28,7 @@ impl<'input> Card<'input> {
 pub struct Day4<'input> {
     day_number: u32,
     input_data: String,
-    file_path: PathBuf,
+    input: Input,
     data: Vec<Card<'input>>,
     cards: HashMap<usize, Card<'input>>,
 }
@@ -45,7 +44,7 @@ impl<'input> Day4<'input> {
         Day4 {
             day_number: 4,
             input_data: String::new(),
-            file_path: get_input_path(2023, 4),
+            input: get_input_path(2023, 4).into(),
             data: Vec::new(),
             cards: HashMap::new(),
         }
@@ -93,7 +92,7 @@ impl<'input> BaseDay for Day4<'input> {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         Ok(self
             .data
             .iter()
@@ -107,7 +106,7 @@ impl<'input> BaseDay for Day4<'input> {
             .to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         let mut cards_processed = self.data.len();
 
         let cache: HashMap<usize, Vec<usize>> = self.process_cards(&self.data);
@@ -121,8 +120,8 @@ impl<'input> BaseDay for Day4<'input> {
         Ok(cards_processed.to_string())
     }
 
-    fn setup(&mut self) {
-        self.input_data = fs::read_to_string(&self.file_path).expect("Error reading input!");
+    fn setup(&mut self) -> AocResult<()> {
+        self.input_data = self.read_file()?;
 
         let input_ref: &'input str = unsafe { std::mem::transmute(&*self.input_data) };
 
@@ -136,10 +135,16 @@ impl<'input> BaseDay for Day4<'input> {
             .iter()
             .map(|card| (card.id, card.clone()))
             .collect();
+
+        Ok(())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -148,8 +153,8 @@ impl<'input> BaseDay for Day4<'input> {
 async fn main() -> Result<(), Box<dyn Error>> {
     let mut day = Day4::new();
     init_logger();
-    common::file::download_input_file(2023, day.day_number).await?;
-    day.setup();
+    day.set_input(common::file::download_input_file(2023, day.day_number).await?);
+    day.setup()?;
     let result_part_1 = time_it!("part_1", { day.part_1()? });
     info!("Part 1: {:?}", result_part_1,);
 
@@ -162,7 +167,6 @@ async fn main() -> Result<(), Box<dyn Error>> {
 #[cfg(test)]
 mod test_day_4 {
     use super::*;
-    use common::file::get_data_dir;
 
     #[test]
     fn parse_input_line_test() {
@@ -177,19 +181,6 @@ mod test_day_4 {
         assert_eq!(expected, result);
     }
 
-    #[test]
-    fn part_1_test() -> Result<(), Box<dyn Error>> {
-        let mut day = Day4::new();
-        day.file_path = get_data_dir(2023, 4).join("example_1.txt");
-        day.setup();
-
-        let expected = String::from("13");
-        let result = day.part_1()?;
-        assert_eq!(expected, result);
-
-        Ok(())
-    }
-
     #[test]
     fn win_card_test() -> Result<(), Box<dyn Error>> {
         let line = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
@@ -200,17 +191,4 @@ mod test_day_4 {
 
         Ok(())
     }
-
-    #[test]
-    fn part_2_test() -> Result<(), Box<dyn Error>> {
-        let mut day = Day4::new();
-        day.file_path = get_data_dir(2023, 4).join("example_1.txt");
-        day.setup();
-
-        let expected = String::from("30");
-        let result = day.part_2()?;
-        assert_eq!(expected, result);
-
-        Ok(())
-    }
 }
@@ -1,11 +1,10 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::time_it;
 use common::utils::init_logger;
 use log::info;
 use std::collections::HashMap;
-use std::error::Error;
-use std::path::PathBuf;
 
 const MAP_ORDER: [&str; 7] = [
     "seed-to-soil",
@@ -124,7 +123,7 @@ impl MapEntry {
 
 pub struct Day5 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
     puzzle_input: PuzzleInput,
 }
 
@@ -138,7 +137,7 @@ impl Day5 {
     pub fn new() -> Day5 {
         Day5 {
             day_number: 5,
-            file_path: get_input_path(2023, 5),
+            input: get_input_path(2023, 5).into(),
             puzzle_input: PuzzleInput::new(),
         }
     }
@@ -178,7 +177,7 @@ impl Day5 {
                         .collect::<Vec<_>>();
                     index += 1;
                 } else {
-                    let map = Day5::parse_map(&lines, index);
+                    let map = Day5::parse_map(lines, index);
                     index += map.entries.len() + 1;
                     puzzle_input.maps.insert(map.name.clone(), map);
                 }
@@ -237,22 +236,28 @@ impl BaseDay for Day5 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         Ok(Day5::min_location(&self.puzzle_input).to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         Ok(Day5::min_location_for_range(&self.puzzle_input).to_string())
     }
 
-    fn setup(&mut self) {
-        let input = std::fs::read_to_string(&self.file_path).unwrap();
+    fn setup(&mut self) -> AocResult<()> {
+        let input = self.read_file()?;
         let lines = input.lines().collect::<Vec<_>>();
         self.puzzle_input = Day5::parse_input(&lines);
+
+        Ok(())
+    }
+
+    fn input(&self) -> &Input {
+        &self.input
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -261,9 +266,9 @@ impl BaseDay for Day5 {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut day = Day5::new();
     init_logger();
-    common::file::download_input_file(2023, day.day_number).await?;
+    day.set_input(common::file::download_input_file(2023, day.day_number).await?);
 
-    day.setup();
+    day.setup()?;
     let result_part_1 = time_it!("part_1", { day.part_1()? });
     info!("Part 1: {:?}", result_part_1,);
 
@@ -276,7 +281,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 #[cfg(test)]
 mod test_day_5 {
     use super::*;
-    use common::file::get_data_dir;
     use rstest::*;
 
     #[fixture]
@@ -350,30 +354,4 @@ mod test_day_5 {
             .0;
         assert_eq!(result, min_destination);
     }
-
-    #[test]
-    fn part_1_test() -> Result<(), Box<dyn Error>> {
-        let mut day = Day5::new();
-        day.file_path = get_data_dir(2023, 5).join("example_1.txt");
-        day.setup();
-
-        let result = day.part_1()?;
-        let expected = String::from("35");
-        assert_eq!(result, expected);
-
-        Ok(())
-    }
-
-    #[test]
-    fn part_2_test() -> Result<(), Box<dyn Error>> {
-        let mut day = Day5::new();
-        day.file_path = get_data_dir(2023, 5).join("example_1.txt");
-        day.setup();
-
-        let result = day.part_2()?;
-        let expected = String::from("46");
-        assert_eq!(result, expected);
-
-        Ok(())
-    }
 }
@@ -1,10 +1,10 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::time_it;
 use common::utils::init_logger;
 use log::info;
 use std::error::Error;
-use std::path::PathBuf;
 
 #[derive(Debug)]
 struct Race {
@@ -130,7 +130,7 @@ impl Race {
 
 pub struct Day6 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Default for Day6 {
@@ -143,7 +143,7 @@ impl Day6 {
     pub fn new() -> Day6 {
         Day6 {
             day_number: 6,
-            file_path: get_input_path(2023, 6),
+            input: get_input_path(2023, 6).into(),
         }
     }
 
@@ -193,8 +193,8 @@ impl BaseDay for Day6 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
-        let (times, records) = Self::parse_input(&self.read_file_into_vec());
+    fn part_1(&mut self) -> AocResult<String> {
+        let (times, records) = Self::parse_input(&self.read_file_into_vec()?);
 
         Ok((0..times.len())
             .map(|race_index| {
@@ -204,14 +204,18 @@ impl BaseDay for Day6 {
             .to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
-        let race = Self::parse_input_part_2(&self.read_file_into_vec());
+    fn part_2(&mut self) -> AocResult<String> {
+        let race = Self::parse_input_part_2(&self.read_file_into_vec()?);
 
         Ok(race.distance_analytical().to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -220,9 +224,9 @@ impl BaseDay for Day6 {
 async fn main() -> Result<(), Box<dyn Error>> {
     let mut day = Day6::new();
     init_logger();
-    common::file::download_input_file(2023, day.day_number).await?;
+    day.set_input(common::file::download_input_file(2023, day.day_number).await?);
 
-    day.setup();
+    day.setup()?;
     let result_part_1 = time_it!("part_1", { day.part_1()? });
     info!("Part 1: {:?}", result_part_1,);
 
@@ -231,39 +235,3 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     Ok(())
 }
-
-#[cfg(test)]
-mod day_6_tests {
-    use super::*;
-    use common::file::get_data_dir;
-
-    #[test]
-    fn test_part_1() -> Result<(), Box<dyn Error>> {
-        let expected = String::from("288");
-
-        let mut day = Day6::new();
-        day.file_path = get_data_dir(2023, 6).join("example_1.txt");
-
-        day.setup();
-        let result = day.part_1()?;
-
-        assert_eq!(result, expected);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_part_2() -> Result<(), Box<dyn Error>> {
-        let expected = String::from("71503");
-
-        let mut day = Day6::new();
-        day.file_path = get_data_dir(2023, 6).join("example_1.txt");
-
-        day.setup();
-        let result = day.part_2()?;
-
-        assert_eq!(result, expected);
-
-        Ok(())
-    }
-}
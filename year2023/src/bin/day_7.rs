@@ -1,14 +1,13 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::time_it;
 use common::utils::init_logger;
 use itertools::Itertools;
 use log::info;
 use std::cmp::Ordering;
 use std::collections::HashMap;
-use std::error::Error;
 use std::fmt::Display;
-use std::path::PathBuf;
 
 const CARD_ORDER: [char; 13] = [
     '2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A',
@@ -192,7 +191,7 @@ impl PartialOrd for Hand {
 
 pub struct Day7 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Default for Day7 {
@@ -205,7 +204,7 @@ impl Day7 {
     pub fn new() -> Day7 {
         Day7 {
             day_number: 7,
-            file_path: get_input_path(2023, 7),
+            input: get_input_path(2023, 7).into(),
         }
     }
 
@@ -216,13 +215,14 @@ impl Day7 {
         (hand, bid)
     }
 
-    fn winnings(&self, use_joker: bool) -> u128 {
-        self.read_file_into_vec()
+    fn winnings(&self, use_joker: bool) -> AocResult<u128> {
+        Ok(self
+            .read_file_into_vec()?
             .iter()
             .map(|line| Self::parse_line(line, use_joker))
             .sorted_by(|a, b| a.0.cmp(&b.0))
             .enumerate()
-            .fold(0, |acc, (index, (_, bid))| acc + (index as u128 + 1) * bid)
+            .fold(0, |acc, (index, (_, bid))| acc + (index as u128 + 1) * bid))
     }
 }
 
@@ -231,16 +231,20 @@ impl BaseDay for Day7 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
-        Ok(self.winnings(false).to_string())
+    fn part_1(&mut self) -> AocResult<String> {
+        Ok(self.winnings(false)?.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
-        Ok(self.winnings(true).to_string())
+    fn part_2(&mut self) -> AocResult<String> {
+        Ok(self.winnings(true)?.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -249,9 +253,9 @@ impl BaseDay for Day7 {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut day = Day7::new();
     init_logger();
-    common::file::download_input_file(2023, day.day_number).await?;
+    day.set_input(common::file::download_input_file(2023, day.day_number).await?);
 
-    day.setup();
+    day.setup()?;
     let result_part_1 = time_it!("part_1", { day.part_1()? });
     info!("Part 1: {:?}", result_part_1,);
 
@@ -264,7 +268,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 #[cfg(test)]
 mod test_day_7 {
     use super::*;
-    use common::file::get_data_dir;
     use rstest::rstest;
 
     #[rstest]
@@ -346,34 +349,4 @@ mod test_day_7 {
 
         assert_eq!(hand_type_1.cmp(&hand_type_2), Ordering::Greater);
     }
-
-    #[test]
-    fn test_part_1() -> Result<(), Box<dyn Error>> {
-        let expected = String::from("6440");
-
-        let mut day = Day7::new();
-        day.file_path = get_data_dir(2023, 7).join("example_1.txt");
-
-        day.setup();
-        let result = day.part_1()?;
-
-        assert_eq!(result, expected);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_part_2() -> Result<(), Box<dyn Error>> {
-        let expected = String::from("5905");
-
-        let mut day = Day7::new();
-        day.file_path = get_data_dir(2023, 7).join("example_1.txt");
-
-        day.setup();
-        let result = day.part_2()?;
-
-        assert_eq!(result, expected);
-
-        Ok(())
-    }
 }
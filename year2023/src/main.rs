@@ -25,36 +25,11 @@ use crate::bin::day_24::Day24;
 use crate::bin::day_25::Day25;
 use common::base_day::BaseDay;
 
-use clap::Parser;
-use env_logger::Env;
-use log::info;
 mod bin;
 
-#[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
-struct Args {
-    /// Days to include in the run
-    #[arg(short, long)]
-    day: Option<u32>,
-}
-
-#[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let args = Args::parse();
-
-    let include_days = if let Some(day_to_run) = args.day {
-        vec![day_to_run]
-    } else {
-        vec![]
-    };
-
-    let env = Env::default()
-        .filter_or("MY_LOG_LEVEL", "info")
-        .write_style_or("MY_LOG_STYLE", "always");
-
-    env_logger::init_from_env(env);
-
-    let days: Vec<Box<dyn BaseDay>> = vec![
+/// Every solved day of the year, used by the runner and the example tests.
+fn days() -> Vec<Box<dyn BaseDay>> {
+    vec![
         Box::new(Day1::new()),
         Box::new(Day2::new()),
         Box::new(Day3::new()),
@@ -80,18 +55,27 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         Box::new(Day23::new()),
         Box::new(Day24::new()),
         Box::new(Day25::new()),
-    ];
+    ]
+}
 
-    for mut day in days {
-        let day_number = day.get_day_number();
-        if include_days.is_empty() || include_days.contains(&day_number) {
-            common::file::download_input_file(2023, day_number).await?;
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    common::runner::run(2023, days).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::examples::Checked;
+    use rstest::rstest;
+    use std::path::PathBuf;
 
-            let result = day.run_day()?;
-            info!("Day {} - part 1: {:?}", result.day, result.part_1);
-            info!("Day {} - part 2: {:?}", result.day, result.part_2);
+    #[rstest]
+    fn example_test(#[files("../data/2023/day_*/example_*.expected.toml")] path: PathBuf) {
+        match common::examples::check_example(days(), &path) {
+            Ok(Checked::Passed) => {}
+            Ok(Checked::NoAnswers) => eprintln!("{}: no answers yet, skipped", path.display()),
+            Err(e) => panic!("{}: {}", path.display(), e),
         }
     }
-
-    Ok(())
 }
@@ -0,0 +1,4 @@
+fn main() {
+    println!("cargo::rerun-if-changed=../data/2024");
+    println!("cargo::rerun-if-changed=data");
+}
@@ -1,20 +1,26 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::utils::init_logger;
 use log::info;
-use std::path::PathBuf;
 use std::str::FromStr;
 
 pub struct Day1 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
+}
+
+impl Default for Day1 {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Day1 {
     pub fn new() -> Day1 {
         Day1 {
             day_number: 1,
-            file_path: get_input_path(2024, 1),
+            input: get_input_path(2024, 1).into(),
         }
     }
 
@@ -40,8 +46,8 @@ impl BaseDay for Day1 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn std::error::Error>> {
-        let (mut col_1, mut col_2) = self.read_lines_into_columns(self.read_file_into_vec());
+    fn part_1(&mut self) -> AocResult<String> {
+        let (mut col_1, mut col_2) = self.read_lines_into_columns(self.read_file_into_vec()?);
 
         col_1.sort();
         col_2.sort();
@@ -54,8 +60,8 @@ impl BaseDay for Day1 {
         Ok(result.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn std::error::Error>> {
-        let (col_1, col_2) = self.read_lines_into_columns(self.read_file_into_vec());
+    fn part_2(&mut self) -> AocResult<String> {
+        let (col_1, col_2) = self.read_lines_into_columns(self.read_file_into_vec()?);
 
         let mut result: u64 = 0;
         for x in col_1.iter() {
@@ -64,8 +70,12 @@ impl BaseDay for Day1 {
         Ok(result.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -74,7 +84,7 @@ impl BaseDay for Day1 {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut day = Day1::new();
     init_logger();
-    common::file::download_input_file(2024, day.day_number).await?;
+    day.set_input(common::file::download_input_file(2024, day.day_number).await?);
 
     let result = day.run_day()?;
     info!("Day {} - part 1: {:?}", result.day, result.part_1);
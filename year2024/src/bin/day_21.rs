@@ -1,9 +1,9 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::utils::{init_logger, manhattan_distance};
 use log::info;
 use std::collections::HashMap;
-use std::path::PathBuf;
 
 const NUMERIC: [char; 12] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', ' '];
 const NUMERIC_KEYPAD: [[char; 3]; 4] = [
@@ -209,7 +209,13 @@ pub struct Day21 {
     sequences: HashMap<DistanceKey, DistanceVal>,
     cache: HashMap<(Vec<char>, usize), u64>,
     numeric_keypad: NumericKeypad,
-    file_path: PathBuf,
+    input: Input,
+}
+
+impl Default for Day21 {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Day21 {
@@ -263,7 +269,7 @@ impl Day21 {
             ]),
             cache: HashMap::new(),
             numeric_keypad: NumericKeypad::new(),
-            file_path: get_input_path(2024, 21),
+            input: get_input_path(2024, 21).into(),
         }
     }
 
@@ -273,17 +279,17 @@ impl Day21 {
             keys: &[char],
             index: usize,
             prev_key: char,
-            current_path: &Vec<char>,
+            current_path: &[char],
             result: &mut Vec<Vec<char>>,
             cache: &HashMap<DistanceKey, DistanceVal>,
         ) {
             if index == keys.len() {
-                result.push(current_path.clone());
+                result.push(current_path.to_vec());
                 return;
             }
 
             for path in cache.get(&(prev_key, keys[index], 0)).unwrap() {
-                let mut new_path = current_path.clone();
+                let mut new_path = current_path.to_vec();
                 new_path.extend(path.1.chars());
                 new_path.push('A');
                 build_seq(keys, index + 1, keys[index], &new_path, result, cache);
@@ -357,9 +363,9 @@ impl BaseDay for Day21 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn std::error::Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let robots = 2;
-        let input = self.read_file_into_vec();
+        let input = self.read_file_into_vec()?;
         let mut result = 0;
         for line in input.iter() {
             result += self.solve(line, robots);
@@ -368,9 +374,9 @@ impl BaseDay for Day21 {
         Ok(result.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn std::error::Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         let robots = 25;
-        let input = self.read_file_into_vec();
+        let input = self.read_file_into_vec()?;
         let mut result = 0;
         for line in input.iter() {
             result += self.solve(line, robots);
@@ -379,8 +385,12 @@ impl BaseDay for Day21 {
         Ok(result.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -389,7 +399,7 @@ impl BaseDay for Day21 {
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut day = Day21::new();
     init_logger();
-    common::file::download_input_file(2024, day.day_number).await?;
+    day.set_input(common::file::download_input_file(2024, day.day_number).await?);
 
     let result = day.run_day()?;
     info!("Day {} - part 1: {:?}", result.day, result.part_1);
@@ -401,43 +411,6 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use std::path::Path;
-
-    #[test]
-    fn part_1_test() {
-        let expected = 126_384.to_string();
-
-        let project_root = Path::new(env!("CARGO_MANIFEST_DIR"))
-            .parent()
-            .unwrap()
-            .to_path_buf();
-        let mut day = Day21::new();
-        let path = project_root.join("data/2024/day_21/example_1.txt");
-        day.file_path = path;
-
-        day.setup();
-        let result = day.part_1();
-
-        assert_eq!(expected, result.unwrap());
-    }
-
-    #[test]
-    fn part_2_test() {
-        let expected = 154_115_708_116_294u64.to_string();
-
-        let project_root = Path::new(env!("CARGO_MANIFEST_DIR"))
-            .parent()
-            .unwrap()
-            .to_path_buf();
-        let mut day = Day21::new();
-        let path = project_root.join("data/2024/day_21/example_1.txt");
-        day.file_path = path;
-
-        day.setup();
-        let result = day.part_2();
-
-        assert_eq!(expected, result.unwrap());
-    }
 
     #[test]
     fn build_seq_test() {
@@ -449,7 +422,7 @@ mod test {
             vec!['<', 'v', '<', 'A', '>', '>', '^', 'A'],
             vec!['<', 'v', '<', 'A', '>', '^', '>', 'A'],
         ];
-        let result = day.sequences(&vec!['A', '<', 'A'], 1);
+        let result = day.sequences(&['A', '<', 'A'], 1);
         assert_eq!(expected, result);
     }
 
@@ -458,7 +431,7 @@ mod test {
         let mut day = Day21::new();
 
         let expected = 18;
-        let result = day.shortest_seq(&vec!['<', 'A'], 2);
+        let result = day.shortest_seq(&['<', 'A'], 2);
         assert_eq!(expected, result);
     }
 
@@ -1,16 +1,16 @@
-use common::file::get_input_path;
+use common::error::{AocError, AocResult};
+use common::file::{Input, get_input_path};
 use common::{base_day::BaseDay, test_utils::init_logger};
 use log::info;
 use rusqlite::Connection;
 use std::error::Error;
-use std::path::PathBuf;
 
 const SECRETS: u128 = 2_000;
 
 fn next_secret(number: u128) -> u128 {
-    let mut result = number ^ (number * 64u128) % 16_777_216;
-    result = result ^ (result / 32u128) % 16_777_216;
-    result = result ^ (result * 2048u128) % 16_777_216;
+    let mut result = number ^ ((number * 64u128) % 16_777_216);
+    result = result ^ ((result / 32u128) % 16_777_216);
+    result = result ^ ((result * 2048u128) % 16_777_216);
 
     result
 }
@@ -46,7 +46,7 @@ fn diffs(prices: &[u128]) -> Vec<i128> {
         .collect()
 }
 
-fn max_bananas(conn: Connection) -> Result<u64, Box<dyn Error>> {
+fn max_bananas(conn: Connection) -> rusqlite::Result<u64> {
     let mut stmt = conn.prepare("
 select qq.diff, sum(qq.price) from (
     select q.diff, q.price as price
@@ -69,7 +69,7 @@ limit 1
     Ok(result as u64)
 }
 
-fn create_tables(conn: &Connection) -> Result<(), Box<dyn Error>> {
+fn create_tables(conn: &Connection) -> rusqlite::Result<()> {
     conn.execute(
         "CREATE TABLE if not exists sequence (secret INTEGER, start_index INTEGER, diff TEXT)",
         (),
@@ -82,14 +82,14 @@ fn create_tables(conn: &Connection) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-fn clear_tables(conn: &Connection) -> Result<(), Box<dyn Error>> {
+fn clear_tables(conn: &Connection) -> rusqlite::Result<()> {
     conn.execute("DELETE FROM sequence;", ())?;
     conn.execute("DELETE FROM price;", ())?;
 
     Ok(())
 }
 
-fn solve(secrets: &[u128]) -> Result<u64, Box<dyn Error>> {
+fn solve(secrets: &[u128]) -> rusqlite::Result<u64> {
     let mut conn = Connection::open("day_22.sqlite")?;
     create_tables(&conn)?;
     clear_tables(&conn)?;
@@ -121,19 +121,25 @@ fn solve(secrets: &[u128]) -> Result<u64, Box<dyn Error>> {
     }
     tx.commit()?;
 
-    Ok(max_bananas(conn)?)
+    max_bananas(conn)
 }
 
 pub struct Day22 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
+}
+
+impl Default for Day22 {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Day22 {
     pub fn new() -> Self {
         Self {
             day_number: 22,
-            file_path: get_input_path(2024, 22),
+            input: get_input_path(2024, 22).into(),
         }
     }
 }
@@ -143,29 +149,26 @@ impl BaseDay for Day22 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let mut result = 0u128;
-        for line in self.read_file_into_vec() {
-            let secret = day_secret(line.parse::<u128>().unwrap());
-            result += secret;
+        for initial_secret in self.input_parsed::<u128>()? {
+            result += day_secret(initial_secret);
         }
 
         Ok(result.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
-        Ok(solve(
-            &self
-                .read_file_into_vec()
-                .iter()
-                .map(|line| line.parse::<u128>().unwrap())
-                .collect::<Vec<u128>>(),
-        )?
-        .to_string())
+    fn part_2(&mut self) -> AocResult<String> {
+        let secrets = self.input_parsed::<u128>()?;
+        Ok(solve(&secrets).map_err(AocError::other)?.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -174,7 +177,7 @@ impl BaseDay for Day22 {
 async fn main() -> Result<(), Box<dyn Error>> {
     let mut day = Day22::new();
     init_logger();
-    common::file::download_input_file(2024, day.day_number).await?;
+    day.set_input(common::file::download_input_file(2024, day.day_number).await?);
 
     let result = day.run_day()?;
     info!("Day {} - part 1: {:?}", result.day, result.part_1);
@@ -186,7 +189,6 @@ async fn main() -> Result<(), Box<dyn Error>> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use std::path::Path;
 
     #[test]
     fn next_secret_test() {
@@ -241,22 +243,6 @@ mod test {
         assert_eq!(result, expected);
     }
 
-    #[test]
-    fn part_1_test() {
-        let mut day = Day22::new();
-        let expected = "37327623";
-
-        let project_root = Path::new(env!("CARGO_MANIFEST_DIR"))
-            .parent()
-            .unwrap()
-            .to_path_buf();
-        let path = project_root.join("data/2024/day_22/example_1.txt");
-        day.file_path = path;
-
-        let result = day.part_1();
-        assert_eq!(result.unwrap(), expected);
-    }
-
     #[test]
     fn day_prices_test() {
         let expected = [3, 0, 6, 5, 4, 4, 6, 4, 4, 2];
@@ -273,21 +259,4 @@ mod test {
         let result: Vec<i128> = diffs(&prices);
         assert_eq!(result, expected);
     }
-
-    #[test]
-    fn part_2_test() {
-        let expected = 23.to_string();
-
-        let mut day = Day22::new();
-
-        let project_root = Path::new(env!("CARGO_MANIFEST_DIR"))
-            .parent()
-            .unwrap()
-            .to_path_buf();
-        let path = project_root.join("data/2024/day_22/example_2.txt");
-        day.file_path = path;
-
-        let result = day.part_2();
-        assert_eq!(result.unwrap(), expected);
-    }
 }
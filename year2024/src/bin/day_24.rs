@@ -1,196 +1,94 @@
+use clap::Parser;
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::{AocError, AocResult, Context};
+use common::file::{Input, get_input_path};
 use common::utils::init_logger;
-use itertools::Itertools;
-use log::info;
-use regex::Regex;
-use std::cmp::Ordering;
-use std::collections::HashMap;
+use log::{Level, debug, info, log_enabled, trace};
 use std::error::Error;
-use std::hash::Hash;
-use std::path::PathBuf;
-use std::{fmt, fs};
-
-#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
-enum OperationType {
-    AND,
-    OR,
-    XOR,
-}
-
-impl OperationType {
-    fn from_string(s: &str) -> OperationType {
-        match s {
-            "AND" => OperationType::AND,
-            "OR" => OperationType::OR,
-            "XOR" => OperationType::XOR,
-            _ => panic!("Unknown operation type: {}", s),
-        }
-    }
-}
-
-impl fmt::Display for OperationType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            OperationType::AND => write!(f, "AND"),
-            OperationType::OR => write!(f, "OR"),
-            OperationType::XOR => write!(f, "XOR"),
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Parsing, evaluation and repair of the gate network, which is meant to be a
+/// ripple-carry adder of the `x` and `y` bits into the `z` bits.
+// This file is both the `day_24` binary and `bin::day_24` of the year crate,
+// which would look for the module in different directories.
+#[path = "day_24/circuit.rs"]
+mod circuit;
+
+use circuit::Circuit;
+
+/// Shows the gates around the lowest wrong bit of the first failing addition,
+/// which is where the carry goes astray.
+fn log_first_failure(circuit: &Circuit) -> AocResult<()> {
+    let Some((x, y)) = circuit.first_failure(100) else {
+        return Ok(());
+    };
+    let wrong_bits = circuit.wrong_bits(x, y)?;
+    trace!("{} + {} gets bits {:?} wrong", x, y, wrong_bits);
+
+    let Some(&lowest) = wrong_bits.first() else {
+        return Ok(());
+    };
+    let stages = circuit.stages();
+    for step in circuit.trace(x, y)? {
+        if (lowest.saturating_sub(1)..=lowest).contains(&stages[step.gate.output]) {
+            trace!("{}", step);
         }
     }
-}
 
-impl Ord for OperationType {
-    fn cmp(&self, other: &Self) -> Ordering {
-        if self == &Self::AND {
-            if other == &Self::AND {
-                Ordering::Equal
-            } else if other == &Self::OR {
-                Ordering::Less
-            } else {
-                Ordering::Less
-            }
-        } else if self == &Self::OR {
-            if other == &Self::AND {
-                Ordering::Greater
-            } else if other == &Self::OR {
-                Ordering::Equal
-            } else {
-                Ordering::Less
-            }
-        } else {
-            if other == &Self::AND {
-                Ordering::Greater
-            } else if other == &Self::OR {
-                Ordering::Greater
-            } else {
-                Ordering::Equal
-            }
-        }
-    }
-}
-
-impl PartialOrd for OperationType {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
+    Ok(())
 }
 
-#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone)]
-struct Gate<'a> {
-    wire_1: &'a str,
-    wire_2: &'a str,
-    output: &'a str,
-    operation: OperationType,
+pub struct Day24 {
+    day_number: u32,
+    input: Input,
 }
 
-impl<'a> fmt::Display for Gate<'a> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "{} = {} {} {}",
-            self.output, self.wire_1, self.operation, self.wire_2
-        )
+impl Default for Day24 {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-fn solve_recursion<'a>(wire: &'a str, x: &[u8], y: &[u8], gates: &[Gate<'a>]) -> u8 {
-    if wire.starts_with('x') {
-        let index: usize = wire[1..=2].parse().unwrap();
-        x[index]
-    } else if wire.starts_with("y") {
-        let index: usize = wire[1..=2].parse().unwrap();
-        y[index]
-    } else {
-        let gate = gates.iter().find(|gate| gate.output == wire).unwrap();
-        let wire_1 = solve_recursion(gate.wire_1, x, y, gates);
-        let wire_2 = solve_recursion(gate.wire_2, x, y, gates);
-
-        match gate.operation {
-            OperationType::AND => wire_1 & wire_2,
-            OperationType::OR => wire_1 | wire_2,
-            OperationType::XOR => wire_1 ^ wire_2,
+impl Day24 {
+    pub fn new() -> Self {
+        Self {
+            day_number: 24,
+            input: get_input_path(2024, 24).into(),
         }
     }
-}
-
-fn make_wire(prefix: char, bit: usize) -> String {
-    format!("{}{:02}", prefix, bit)
-}
 
-fn solve<'a>(wires: &HashMap<&'a str, u8>, gates: &[Gate<'a>], output_wires: &[&'a str]) -> String {
-    let x = [0; 45]
-        .iter()
-        .enumerate()
-        .map(|(index, _)| wires[make_wire('x', index).as_str()])
-        .collect_vec();
-    let y = [0; 45]
-        .iter()
-        .enumerate()
-        .map(|(index, _)| wires[make_wire('y', index).as_str()])
-        .collect_vec();
-
-    output_wires
-        .iter()
-        .map(|w| solve_recursion(w, &x, &y, gates))
-        .join("")
-}
+    /// Writes the circuit as Graphviz DOT with the outputs breaking the adder
+    /// pattern in red, e.g. for `dot -Tsvg circuit.dot -o circuit.svg`.
+    pub fn write_dot(&self, path: &Path) -> AocResult<()> {
+        let data = self.input_lines()?;
+        let circuit = Circuit::parse(&data)?;
 
-fn create_input<'a>(
-    input: &'a [&'a str],
-) -> Result<(HashMap<&'a str, u8>, Vec<Gate<'a>>, Vec<&'a str>), Box<dyn Error>> {
-    let mut gates = Vec::new();
-    let mut wires = HashMap::new();
-
-    for line in input {
-        if line.contains("->") {
-            let re = Regex::new(r"^(.*)\s(\w{2,3})\s(.*)\s->\s(.*)$")?;
-            let captures = re.captures(&line).unwrap();
-
-            gates.push(Gate {
-                wire_1: captures.get(1).unwrap().as_str(),
-                wire_2: captures.get(3).unwrap().as_str(),
-                output: captures.get(4).unwrap().as_str(),
-                operation: OperationType::from_string(captures.get(2).unwrap().as_str()),
-            });
-        } else if !line.is_empty() {
-            let (wire, value) = line.split_once(": ").unwrap();
-            let parsed_value = value.parse::<u8>()?;
-
-            wires.insert(wire, parsed_value);
-        }
+        fs::write(path, circuit.to_dot(&circuit.find_miswired()))
+            .with_context(|| format!("writing {}", path.display()))
     }
 
-    let output_wires = gates
-        .iter()
-        .filter_map(|gate| {
-            if gate.output.starts_with("z") {
-                Some(gate.output)
-            } else {
-                None
-            }
-        })
-        .sorted()
-        .rev()
-        .collect_vec();
-
-    Ok((wires, gates, output_wires))
-}
-
-fn integer(input: &str) -> Result<u64, Box<dyn Error>> {
-    Ok(u64::from_str_radix(input, 2)?)
-}
-
-pub struct Day24 {
-    day_number: u32,
-    file_path: PathBuf,
-}
+    /// Every gate with its inputs and output while adding `x` and `y`, in the
+    /// order they are evaluated, followed by the sum and its wrong bits.
+    pub fn trace(&self, x: u64, y: u64) -> AocResult<String> {
+        let data = self.input_lines()?;
+        let circuit = Circuit::parse(&data)?;
 
-impl Day24 {
-    pub fn new() -> Self {
-        Self {
-            day_number: 24,
-            file_path: get_input_path(2024, 24),
+        let mut output = String::new();
+        for step in circuit.trace(x, y)? {
+            let _ = writeln!(output, "{}", step);
         }
+        let _ = writeln!(
+            output,
+            "{} + {} = {}, wrong bits: {:?}",
+            x,
+            y,
+            circuit.add(x, y)?,
+            circuit.wrong_bits(x, y)?
+        );
+
+        Ok(output)
     }
 }
 
@@ -199,42 +97,96 @@ impl BaseDay for Day24 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
-        let file_content = fs::read_to_string(&self.file_path)?;
-        let data = file_content.lines().collect_vec();
+    fn part_1(&mut self) -> AocResult<String> {
+        let data = self.input_lines()?;
+        let circuit = Circuit::parse(&data)?;
 
-        let (wires, gates, output_wires) = create_input(&data)?;
+        Ok(circuit.output()?.to_string())
+    }
 
-        let result = solve(&wires, &gates, &output_wires);
-        let int = integer(&result)?;
+    fn part_2(&mut self) -> AocResult<String> {
+        let data = self.input_lines()?;
+        let circuit = Circuit::parse(&data)?;
+
+        let miswired = circuit.find_miswired();
+        debug!("Wires breaking the adder pattern: {:?}", miswired);
+        if log_enabled!(Level::Trace) {
+            trace!("{}", circuit.to_dot(&miswired));
+            if let Err(e) = log_first_failure(&circuit) {
+                trace!("Cannot trace the circuit: {}", e);
+            }
+        }
 
-        Ok(int.to_string())
+        let swaps = circuit
+            .repair(&miswired)
+            .context("no pairing of the miswired outputs makes an adder")?;
+        for (a, b) in &swaps {
+            debug!("Swapping {} and {}", a, b);
+        }
+
+        let mut wires: Vec<&str> = swaps.iter().flat_map(|&(a, b)| [a, b]).collect();
+        wires.sort_unstable();
+        Ok(wires.join(","))
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
-        // Part 2 still coming...
-        unimplemented!()
+    fn input(&self) -> &Input {
+        &self.input
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
+/// Solves day 24, or inspects the circuit instead when `--dot` or `--trace` is given.
+#[allow(dead_code)]
+#[derive(Parser, Debug)]
+struct Args {
+    /// Write the circuit to this Graphviz DOT file, miswired outputs in red
+    #[arg(long)]
+    dot: Option<PathBuf>,
+
+    /// Print the value of every gate while adding x and y, e.g. `--trace 3,5`
+    #[arg(long, value_parser = parse_operands)]
+    trace: Option<(u64, u64)>,
+}
+
+#[allow(dead_code)]
+fn parse_operands(value: &str) -> AocResult<(u64, u64)> {
+    let parse = |n: &str| {
+        n.trim()
+            .parse()
+            .map_err(|e| AocError::parse(format!("{}: {}", n, e)))
+    };
+    let (x, y) = value
+        .split_once(',')
+        .ok_or_else(|| AocError::parse("expected x,y"))?;
+
+    Ok((parse(x)?, parse(y)?))
+}
+
 #[allow(dead_code)]
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    let args = Args::parse();
     let mut day = Day24::new();
     init_logger();
-    common::file::download_input_file(2024, day.day_number).await?;
-
-    day.setup();
+    day.set_input(common::file::download_input_file(2024, day.day_number).await?);
 
-    // let result_1 = day.part_1()?;
-    // info!("Day {} - part 1: {:?}", day.day_number, result_1);
+    if let Some(path) = &args.dot {
+        day.write_dot(path)?;
+        info!("Circuit written to {}", path.display());
+    }
+    if let Some((x, y)) = args.trace {
+        print!("{}", day.trace(x, y)?);
+    }
+    if args.dot.is_some() || args.trace.is_some() {
+        return Ok(());
+    }
 
-    let result_2 = day.part_2()?;
-    info!("Day {} - part 2: {:?}", day.day_number, result_2);
+    let result = day.run_day()?;
+    info!("Day {} - part 1: {:?}", result.day, result.part_1);
+    info!("Day {} - part 2: {:?}", result.day, result.part_2);
 
     Ok(())
 }
@@ -0,0 +1,590 @@
+use common::error::{AocError, AocResult, Context};
+use common::parse::{Pattern, key_value};
+use itertools::Itertools;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::fmt::Write;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone)]
+pub enum OperationType {
+    And,
+    Or,
+    Xor,
+}
+
+impl OperationType {
+    pub fn from_string(s: &str) -> AocResult<OperationType> {
+        match s {
+            "AND" => Ok(OperationType::And),
+            "OR" => Ok(OperationType::Or),
+            "XOR" => Ok(OperationType::Xor),
+            _ => Err(AocError::parse(format!("unknown operation type: {}", s))),
+        }
+    }
+
+    fn apply(&self, a: u8, b: u8) -> u8 {
+        match self {
+            OperationType::And => a & b,
+            OperationType::Or => a | b,
+            OperationType::Xor => a ^ b,
+        }
+    }
+
+    fn colour(&self) -> &'static str {
+        match self {
+            OperationType::And => "lightblue",
+            OperationType::Or => "palegreen",
+            OperationType::Xor => "gold",
+        }
+    }
+}
+
+impl fmt::Display for OperationType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            OperationType::And => write!(f, "AND"),
+            OperationType::Or => write!(f, "OR"),
+            OperationType::Xor => write!(f, "XOR"),
+        }
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Hash, Clone)]
+pub struct Gate<'a> {
+    pub wire_1: &'a str,
+    pub wire_2: &'a str,
+    pub output: &'a str,
+    pub operation: OperationType,
+}
+
+impl<'a> Gate<'a> {
+    fn has_input(&self, wire: &str) -> bool {
+        self.wire_1 == wire || self.wire_2 == wire
+    }
+
+    /// Whether the gate reads `x<n>` and `y<n>` directly.
+    fn reads_inputs(&self) -> bool {
+        let is_input = |wire: &str| wire.starts_with('x') || wire.starts_with('y');
+        is_input(self.wire_1) && is_input(self.wire_2)
+    }
+}
+
+impl<'a> fmt::Display for Gate<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} = {} {} {}",
+            self.output, self.wire_1, self.operation, self.wire_2
+        )
+    }
+}
+
+const MAX_SWAPS: usize = 4;
+
+pub fn make_wire(prefix: char, bit: usize) -> String {
+    format!("{}{:02}", prefix, bit)
+}
+
+/// The bit of an `x`, `y` or `z` wire.
+fn wire_bit(wire: &str) -> Option<usize> {
+    match wire.as_bytes().first() {
+        Some(b'x' | b'y' | b'z') => wire[1..].parse().ok(),
+        _ => None,
+    }
+}
+
+/// One gate firing during a simulation.
+#[derive(Debug, Clone)]
+pub struct TraceStep<'a> {
+    pub gate: Gate<'a>,
+    pub inputs: (u8, u8),
+    pub value: u8,
+}
+
+impl<'a> fmt::Display for TraceStep<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} = {} {} {} = {}",
+            self.gate, self.inputs.0, self.gate.operation, self.inputs.1, self.value
+        )
+    }
+}
+
+/// A network of gates with its initial wire values.
+#[derive(Debug, Clone)]
+pub struct Circuit<'a> {
+    pub wires: HashMap<&'a str, u8>,
+    pub gates: Vec<Gate<'a>>,
+}
+
+impl<'a> Circuit<'a> {
+    pub fn parse(input: &[&'a str]) -> AocResult<Circuit<'a>> {
+        let mut gates = Vec::new();
+        let mut wires = HashMap::new();
+
+        let gate_pattern = Pattern::new("{} {} {} -> {}");
+        for (index, line) in input.iter().enumerate() {
+            if line.contains("->") {
+                let fields = gate_pattern
+                    .captures(line)
+                    .map_err(|e| e.at_line(index + 1))?;
+
+                gates.push(Gate {
+                    wire_1: fields[0].1,
+                    wire_2: fields[2].1,
+                    output: fields[3].1,
+                    operation: OperationType::from_string(fields[1].1)
+                        .with_context(|| format!("line {}", index + 1))?,
+                });
+            } else if !line.is_empty() {
+                let (wire, value) = key_value(line).map_err(|e| e.at_line(index + 1))?;
+                let parsed_value = value
+                    .parse::<u8>()
+                    .with_context(|| format!("wire {}", wire))?;
+
+                wires.insert(wire, parsed_value);
+            }
+        }
+
+        Ok(Circuit { wires, gates })
+    }
+
+    /// Number of bits of each operand, i.e. of `x` and of `y`.
+    pub fn input_bits(&self) -> usize {
+        self.wires.keys().filter(|w| w.starts_with('x')).count()
+    }
+
+    fn output_wires(&self) -> Vec<&'a str> {
+        self.gates
+            .iter()
+            .map(|gate| gate.output)
+            .filter(|wire| wire.starts_with('z'))
+            .sorted()
+            .collect()
+    }
+
+    /// Gate indices ordered so every gate comes after the gates feeding it. A
+    /// wrong swap can close a loop, which is reported as an error.
+    pub fn topological_order(&self) -> AocResult<Vec<usize>> {
+        let producer: HashMap<&str, usize> = self
+            .gates
+            .iter()
+            .enumerate()
+            .map(|(index, gate)| (gate.output, index))
+            .collect();
+
+        let mut pending = vec![0; self.gates.len()];
+        let mut consumers: Vec<Vec<usize>> = vec![Vec::new(); self.gates.len()];
+        for (index, gate) in self.gates.iter().enumerate() {
+            for wire in [gate.wire_1, gate.wire_2] {
+                if let Some(&source) = producer.get(wire) {
+                    pending[index] += 1;
+                    consumers[source].push(index);
+                }
+            }
+        }
+
+        let mut ready: VecDeque<usize> = (0..self.gates.len())
+            .filter(|&index| pending[index] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.gates.len());
+        while let Some(index) = ready.pop_front() {
+            order.push(index);
+            for &consumer in &consumers[index] {
+                pending[consumer] -= 1;
+                if pending[consumer] == 0 {
+                    ready.push_back(consumer);
+                }
+            }
+        }
+
+        if order.len() != self.gates.len() {
+            return Err(AocError::other("the circuit contains a loop"));
+        }
+
+        Ok(order)
+    }
+
+    /// Every wire's value once the signals have propagated, one gate at a time
+    /// in topological order.
+    pub fn evaluate(&self, wires: &HashMap<&'a str, u8>) -> AocResult<HashMap<&'a str, u8>> {
+        let mut values = wires.clone();
+        for index in self.topological_order()? {
+            let gate = &self.gates[index];
+            let input = |wire: &str| {
+                values
+                    .get(wire)
+                    .copied()
+                    .ok_or_else(|| AocError::other(format!("wire {} has no value", wire)))
+            };
+            let value = gate
+                .operation
+                .apply(input(gate.wire_1)?, input(gate.wire_2)?);
+            values.insert(gate.output, value);
+        }
+
+        Ok(values)
+    }
+
+    fn read_number(&self, values: &HashMap<&'a str, u8>) -> u64 {
+        self.output_wires()
+            .iter()
+            .enumerate()
+            .map(|(bit, wire)| (values[wire] as u64) << bit)
+            .sum()
+    }
+
+    /// The number on the `z` wires for the initial wire values.
+    pub fn output(&self) -> AocResult<u64> {
+        Ok(self.read_number(&self.evaluate(&self.wires)?))
+    }
+
+    fn input_wires(&self, x: u64, y: u64) -> AocResult<HashMap<&'a str, u8>> {
+        let mut wires = HashMap::new();
+        for bit in 0..self.input_bits() {
+            for (prefix, number) in [('x', x), ('y', y)] {
+                let wire = make_wire(prefix, bit);
+                let Some((&name, _)) = self.wires.get_key_value(wire.as_str()) else {
+                    return Err(AocError::other(format!("missing input wire {}", wire)));
+                };
+                wires.insert(name, ((number >> bit) & 1) as u8);
+            }
+        }
+
+        Ok(wires)
+    }
+
+    /// Runs the circuit on other `x` and `y` values.
+    pub fn add(&self, x: u64, y: u64) -> AocResult<u64> {
+        Ok(self.read_number(&self.evaluate(&self.input_wires(x, y)?)?))
+    }
+
+    /// Every gate of an addition in the order it fires, with the values it
+    /// read and wrote.
+    pub fn trace(&self, x: u64, y: u64) -> AocResult<Vec<TraceStep<'a>>> {
+        let values = self.evaluate(&self.input_wires(x, y)?)?;
+
+        Ok(self
+            .topological_order()?
+            .into_iter()
+            .map(|index| {
+                let gate = &self.gates[index];
+                TraceStep {
+                    gate: gate.clone(),
+                    inputs: (values[gate.wire_1], values[gate.wire_2]),
+                    value: values[gate.output],
+                }
+            })
+            .collect())
+    }
+
+    /// The `z` bits that differ from the real sum of `x` and `y`.
+    pub fn wrong_bits(&self, x: u64, y: u64) -> AocResult<Vec<usize>> {
+        let difference = self.add(x, y)? ^ (x + y);
+
+        Ok((0..64).filter(|bit| difference >> bit & 1 == 1).collect())
+    }
+
+    /// The full-adder stage of every wire: its own bit for `x`, `y` and `z`
+    /// wires, otherwise the highest stage among the gate's inputs. Stages only
+    /// grow and are bounded by the top bit, so this also settles when a bad
+    /// swap has closed a loop.
+    pub fn stages(&self) -> HashMap<&'a str, usize> {
+        let mut stages: HashMap<&'a str, usize> = HashMap::new();
+        for &wire in self.wires.keys() {
+            if let Some(bit) = wire_bit(wire) {
+                stages.insert(wire, bit);
+            }
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for gate in &self.gates {
+                let stage = wire_bit(gate.output).unwrap_or_else(|| {
+                    [gate.wire_1, gate.wire_2]
+                        .iter()
+                        .filter_map(|wire| stages.get(wire))
+                        .copied()
+                        .max()
+                        .unwrap_or(0)
+                });
+                if stages.insert(gate.output, stage) != Some(stage) {
+                    changed = true;
+                }
+            }
+        }
+
+        stages
+    }
+
+    /// A Graphviz drawing of the circuit. Each gate is drawn as the wire it
+    /// writes, coloured by operation, and every full-adder stage is a
+    /// cluster. The `highlight`ed wires get a red border.
+    pub fn to_dot(&self, highlight: &[&str]) -> String {
+        let stages = self.stages();
+        let mut by_stage: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+
+        for wire in self.wires.keys().sorted() {
+            by_stage
+                .entry(stages[wire])
+                .or_default()
+                .push(format!("\"{}\" [shape=plaintext];", wire));
+        }
+        for gate in self.gates.iter().sorted_by_key(|gate| gate.output) {
+            let border = if highlight.contains(&gate.output) {
+                ", color=red, penwidth=3"
+            } else {
+                ""
+            };
+            by_stage
+                .entry(stages[gate.output])
+                .or_default()
+                .push(format!(
+                    "\"{}\" [label=\"{}\\n{}\", fillcolor={}{}];",
+                    gate.output,
+                    gate.output,
+                    gate.operation,
+                    gate.operation.colour(),
+                    border
+                ));
+        }
+
+        let mut dot = String::from(
+            "digraph circuit {\n    rankdir=LR;\n    node [shape=box, style=filled];\n",
+        );
+        for (stage, nodes) in &by_stage {
+            let _ = writeln!(dot, "    subgraph cluster_bit_{:02} {{", stage);
+            let _ = writeln!(dot, "        label=\"bit {}\";", stage);
+            for node in nodes {
+                let _ = writeln!(dot, "        {}", node);
+            }
+            let _ = writeln!(dot, "    }}");
+        }
+        for gate in self.gates.iter().sorted_by_key(|gate| gate.output) {
+            for input in [gate.wire_1, gate.wire_2] {
+                let _ = writeln!(dot, "    \"{}\" -> \"{}\";", input, gate.output);
+            }
+        }
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    /// The same circuit with the outputs of each pair of gates exchanged.
+    pub fn with_swaps(&self, swaps: &[(&'a str, &'a str)]) -> Circuit<'a> {
+        let mut circuit = self.clone();
+        for gate in circuit.gates.iter_mut() {
+            for &(a, b) in swaps {
+                if gate.output == a {
+                    gate.output = b;
+                } else if gate.output == b {
+                    gate.output = a;
+                }
+            }
+        }
+
+        circuit
+    }
+
+    /// Output wires that break the gate pattern of a ripple-carry adder. Each
+    /// full-adder stage `n` is
+    ///
+    /// ```text
+    /// s = x_n XOR y_n      z_n = s XOR carry_in
+    /// a = x_n AND y_n      b = s AND carry_in      carry_out = a OR b
+    /// ```
+    ///
+    /// with a half adder for bit 0 and the last carry going straight to the top
+    /// `z`. So `z` wires come from XORs, an XOR of inputs feeds the stage's XOR,
+    /// every other XOR writes a `z`, and ANDs only feed the carry's OR.
+    pub fn find_miswired(&self) -> Vec<&'a str> {
+        let last_z = make_wire('z', self.input_bits());
+        let feeds = |wire: &str, operation: OperationType| {
+            self.gates
+                .iter()
+                .any(|gate| gate.operation == operation && gate.has_input(wire))
+        };
+        let first_bit = |gate: &Gate| gate.has_input("x00");
+
+        let mut wrong: HashSet<&'a str> = HashSet::new();
+        for gate in &self.gates {
+            let writes_z = gate.output.starts_with('z');
+            let is_wrong = match gate.operation {
+                _ if gate.output == last_z => gate.operation != OperationType::Or,
+                OperationType::Xor if gate.reads_inputs() => {
+                    // x00 XOR y00 is z00 itself.
+                    if first_bit(gate) {
+                        gate.output != "z00"
+                    } else {
+                        writes_z || !feeds(gate.output, OperationType::Xor)
+                    }
+                }
+                OperationType::Xor => !writes_z,
+                OperationType::And if first_bit(gate) => writes_z,
+                OperationType::And => writes_z || !feeds(gate.output, OperationType::Or),
+                OperationType::Or => writes_z,
+            };
+            if is_wrong {
+                wrong.insert(gate.output);
+            }
+        }
+
+        wrong.into_iter().sorted().collect()
+    }
+
+    /// Adds pseudo-random numbers, plus every single bit, and compares with
+    /// real addition.
+    pub fn verify(&self, trials: usize) -> bool {
+        self.first_failure(trials).is_none()
+    }
+
+    /// The first of the [`Circuit::verify`] additions that goes wrong.
+    pub fn first_failure(&self, trials: usize) -> Option<(u64, u64)> {
+        let bits = self.input_bits();
+        let mask = if bits >= 64 {
+            u64::MAX
+        } else {
+            (1 << bits) - 1
+        };
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state & mask
+        };
+
+        let single_bits =
+            (0..bits).flat_map(|bit| [(1 << bit, 0), (0, 1 << bit), (1 << bit, 1 << bit)]);
+        let random_pairs: Vec<(u64, u64)> = (0..trials).map(|_| (random(), random())).collect();
+
+        single_bits
+            .chain(random_pairs)
+            .find(|&(x, y)| !self.add(x, y).is_ok_and(|z| z == x + y))
+    }
+
+    /// Pairs up the miswired outputs so the adder works again, trying each way
+    /// of pairing them and keeping the one that passes [`Circuit::verify`].
+    /// The puzzle swaps four pairs; a circuit with many more suspects is not
+    /// an adder at all and is given up on.
+    pub fn repair(&self, miswired: &[&'a str]) -> Option<Vec<(&'a str, &'a str)>> {
+        fn pairings<'a>(wires: &[&'a str]) -> Vec<Vec<(&'a str, &'a str)>> {
+            let Some((&first, rest)) = wires.split_first() else {
+                return vec![Vec::new()];
+            };
+
+            let mut result = Vec::new();
+            for (index, &partner) in rest.iter().enumerate() {
+                let mut others = rest.to_vec();
+                others.remove(index);
+                for mut pairs in pairings(&others) {
+                    pairs.push((first, partner));
+                    result.push(pairs);
+                }
+            }
+            result
+        }
+
+        if !miswired.len().is_multiple_of(2) || miswired.len() > 2 * MAX_SWAPS {
+            return None;
+        }
+
+        pairings(miswired)
+            .into_iter()
+            .find(|swaps| self.with_swaps(swaps).verify(100))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses a circuit from an indented string literal.
+    fn adder(lines: &str) -> Circuit<'_> {
+        let lines: Vec<&str> = lines.lines().map(str::trim).collect();
+        Circuit::parse(&lines).unwrap()
+    }
+
+    /// A correct 3-bit ripple-carry adder.
+    const ADDER: &str = "x00: 0
+        x01: 0
+        x02: 0
+        y00: 0
+        y01: 0
+        y02: 0
+
+        x00 XOR y00 -> z00
+        x00 AND y00 -> c00
+        x01 XOR y01 -> s01
+        s01 XOR c00 -> z01
+        x01 AND y01 -> a01
+        s01 AND c00 -> b01
+        a01 OR b01 -> c01
+        x02 XOR y02 -> s02
+        s02 XOR c01 -> z02
+        x02 AND y02 -> a02
+        s02 AND c01 -> b02
+        a02 OR b02 -> z03";
+
+    #[test]
+    fn evaluate_test() -> AocResult<()> {
+        let circuit = adder(ADDER);
+        assert_eq!(circuit.input_bits(), 3);
+        assert_eq!(circuit.add(5, 7)?, 12);
+        assert_eq!(circuit.output()?, 0);
+        assert!(circuit.verify(20));
+        assert!(circuit.find_miswired().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn repair_test() {
+        let circuit = adder(ADDER).with_swaps(&[("z01", "c01")]);
+        assert!(!circuit.verify(20));
+
+        let miswired = circuit.find_miswired();
+        assert_eq!(miswired, vec!["c01", "z01"]);
+        assert_eq!(circuit.repair(&miswired), Some(vec![("c01", "z01")]));
+    }
+
+    #[test]
+    fn trace_test() -> AocResult<()> {
+        let circuit = adder(ADDER).with_swaps(&[("z01", "c01")]);
+        assert_eq!(circuit.wrong_bits(1, 1)?, vec![1, 2]);
+
+        let trace = circuit.trace(1, 1)?;
+        assert_eq!(trace.len(), circuit.gates.len());
+        let carry = trace.iter().find(|step| step.gate.output == "c00").unwrap();
+        assert_eq!(carry.to_string(), "c00 = x00 AND y00 = 1 AND 1 = 1");
+
+        let stages = circuit.stages();
+        assert_eq!(stages["c00"], 0);
+        assert_eq!(stages["b01"], 1);
+        assert_eq!(stages["z03"], 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dot_test() {
+        let dot = adder(ADDER).to_dot(&["z01"]);
+        assert!(dot.starts_with("digraph circuit {\n"));
+        assert!(dot.contains("    subgraph cluster_bit_01 {\n        label=\"bit 1\";\n"));
+        assert!(
+            dot.contains("\"z01\" [label=\"z01\\nXOR\", fillcolor=gold, color=red, penwidth=3];")
+        );
+        assert!(dot.contains("\"a01\" [label=\"a01\\nAND\", fillcolor=lightblue];"));
+        assert!(dot.contains("    \"s01\" -> \"z01\";\n    \"c00\" -> \"z01\";\n"));
+    }
+
+    #[test]
+    fn loop_test() {
+        let circuit = adder(ADDER).with_swaps(&[("s01", "z01")]);
+        assert!(circuit.topological_order().is_err());
+        assert_eq!(circuit.stages()["c01"], 1);
+        assert!(!circuit.verify(1));
+    }
+}
@@ -1,21 +1,27 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::{AocError, AocResult};
+use common::file::{Input, get_input_path};
 use common::utils::init_logger;
 use log::info;
 use std::array;
 use std::error::Error;
-use std::path::PathBuf;
 
 pub struct Day25 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
+}
+
+impl Default for Day25 {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Day25 {
     pub fn new() -> Day25 {
         Day25 {
             day_number: 25,
-            file_path: get_input_path(2024, 25),
+            input: get_input_path(2024, 25).into(),
         }
     }
 }
@@ -25,11 +31,11 @@ impl BaseDay for Day25 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let mut locks = Vec::new();
         let mut keys = Vec::new();
 
-        self.read_file_into_vec_of_vec()
+        self.read_file_into_vec_of_vec()?
             .chunks(8)
             .for_each(|slice| {
                 let line = &slice[0];
@@ -67,12 +73,16 @@ impl BaseDay for Day25 {
         Ok(count.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
-        Ok(String::new())
+    fn part_2(&mut self) -> AocResult<String> {
+        Err(AocError::Unimplemented)
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -81,35 +91,10 @@ impl BaseDay for Day25 {
 async fn main() -> Result<(), Box<dyn Error>> {
     let mut day = Day25::new();
     init_logger();
-    common::file::download_input_file(2024, day.day_number).await?;
+    day.set_input(common::file::download_input_file(2024, day.day_number).await?);
 
     let result_1 = day.part_1()?;
     info!("Day {} - part 1: {:?}", day.day_number, result_1);
 
     Ok(())
 }
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    use common::file::get_data_dir;
-    use common::test_utils::init_logger;
-
-    #[test]
-    fn part_1_test() -> Result<(), Box<dyn Error>> {
-        init_logger();
-        let expected = String::from("3");
-
-        let dir = get_data_dir(2024, 25);
-
-        let mut day = Day25::new();
-        day.file_path = dir.join("example.txt");
-        day.setup();
-
-        let result = day.part_1()?;
-
-        assert_eq!(expected, result);
-
-        Ok(())
-    }
-}
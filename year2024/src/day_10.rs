@@ -1,9 +1,8 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::grid::{Grid, Point};
 use std::collections::HashSet;
-use std::error::Error;
-use std::path::PathBuf;
 
 #[derive(Debug, Clone, Ord, Eq, PartialOrd, PartialEq)]
 struct Node {
@@ -13,14 +12,14 @@ struct Node {
 
 pub struct Day10 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Day10 {
     pub fn new() -> Day10 {
         Day10 {
             day_number: 10,
-            file_path: get_input_path(2024, 10),
+            input: get_input_path(2024, 10).into(),
         }
     }
 
@@ -74,9 +73,9 @@ impl BaseDay for Day10 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
-        let input = self.read_file_into_vec_of_vec();
+        let input = self.read_file_into_vec_of_vec()?;
         let grid = Grid::from_vector(&input);
         let trailheads = grid.filter('0');
 
@@ -94,9 +93,9 @@ impl BaseDay for Day10 {
         Ok(result.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
-        let input = self.read_file_into_vec_of_vec();
+        let input = self.read_file_into_vec_of_vec()?;
         let grid = Grid::from_vector(&input);
         let trailheads = grid.filter('0');
 
@@ -116,7 +115,11 @@ impl BaseDay for Day10 {
         Ok(result.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
@@ -1,31 +1,30 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use std::collections::HashMap;
-use std::error::Error;
-use std::path::PathBuf;
 
 pub struct Day11 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Day11 {
     pub fn new() -> Day11 {
         Day11 {
             day_number: 11,
-            file_path: get_input_path(2024, 11),
+            input: get_input_path(2024, 11).into(),
         }
     }
 
     fn blink_stone_tuple(&self, stone: u64) -> (u64, Option<u64>) {
         if stone == 0 {
             (1, None)
-        } else if stone.to_string().len() % 2 == 0 {
+        } else if stone.to_string().len().is_multiple_of(2) {
             let stone_string = stone.to_string();
-            let first_stone = *&stone_string.as_str()[0..stone_string.len() / 2]
+            let first_stone = stone_string.as_str()[0..stone_string.len() / 2]
                 .parse::<u64>()
                 .unwrap();
-            let second_stone = *&stone_string.as_str()[stone_string.len() / 2..stone_string.len()]
+            let second_stone = stone_string.as_str()[stone_string.len() / 2..stone_string.len()]
                 .parse::<u64>()
                 .unwrap();
             (first_stone, Some(second_stone))
@@ -45,8 +44,8 @@ impl Day11 {
             for (stone, count) in counts {
                 let blink_result = self.blink_stone_tuple(stone);
                 *tmp.entry(blink_result.0).or_insert(0) += count;
-                if blink_result.1.is_some() {
-                    *tmp.entry(blink_result.1.unwrap()).or_insert(0) += count;
+                if let Some(second_stone) = blink_result.1 {
+                    *tmp.entry(second_stone).or_insert(0) += count;
                 }
             }
             counts = tmp;
@@ -60,12 +59,12 @@ impl BaseDay for Day11 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         Ok(self
             .blink_for(
                 25,
                 &self
-                    .read_file_into_vec()
+                    .read_file_into_vec()?
                     .first()
                     .unwrap()
                     .split(' ')
@@ -75,12 +74,12 @@ impl BaseDay for Day11 {
             .to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         Ok(self
             .blink_for(
                 75,
                 &self
-                    .read_file_into_vec()
+                    .read_file_into_vec()?
                     .first()
                     .unwrap()
                     .split(' ')
@@ -90,7 +89,11 @@ impl BaseDay for Day11 {
             .to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
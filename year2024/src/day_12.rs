@@ -1,11 +1,10 @@
 use crate::day_12::Segment::{Horizontal, Vertical};
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::grid::{Grid, Point};
 use std::collections::{HashMap, HashSet};
-use std::error::Error;
 use std::mem::swap;
-use std::path::PathBuf;
 
 #[derive(Debug)]
 enum Segment {
@@ -25,14 +24,14 @@ enum Segment {
 
 pub struct Day12 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Day12 {
     pub fn new() -> Day12 {
         Day12 {
             day_number: 12,
-            file_path: get_input_path(2024, 12),
+            input: get_input_path(2024, 12).into(),
         }
     }
 
@@ -44,15 +43,14 @@ impl Day12 {
     ) {
         let value = *grid.get(current_position).unwrap();
         grid.update(current_position, '.');
-        areas.entry(value).or_insert(Vec::new());
+        areas.entry(value).or_default();
 
         let mut current_set = HashSet::new();
         current_set.insert(current_position);
 
         let mut positions_to_visit = vec![current_position];
 
-        while positions_to_visit.len() > 0 {
-            let p = positions_to_visit.pop().unwrap();
+        while let Some(p) = positions_to_visit.pop() {
             let possible_neighbours = vec![(-1, 0), (1, 0), (0, -1), (0, 1)];
             for possible_neighbour in possible_neighbours {
                 let coordinate = p.add(possible_neighbour.0, possible_neighbour.1);
@@ -68,7 +66,7 @@ impl Day12 {
             }
         }
 
-        let s = areas.get_mut(&value).take().unwrap();
+        let s = areas.get_mut(&value).unwrap();
         s.push(current_set);
     }
 
@@ -310,8 +308,8 @@ impl Day12 {
         self.calc_sides(area, grid) * self.calc_area(area)
     }
 
-    fn build_hash_map(&self) -> HashMap<char, Vec<HashSet<Point>>> {
-        let mut grid = Grid::from_vector(&self.read_file_into_vec_of_vec());
+    fn build_hash_map(&self) -> AocResult<HashMap<char, Vec<HashSet<Point>>>> {
+        let mut grid = Grid::from_vector(&self.read_file_into_vec_of_vec()?);
         let mut areas: HashMap<char, Vec<HashSet<Point>>> = HashMap::new();
 
         let mut current_position = grid.find_different_than('.');
@@ -320,7 +318,7 @@ impl Day12 {
             current_position = grid.find_different_than('.');
         }
 
-        areas
+        Ok(areas)
     }
 }
 
@@ -329,9 +327,9 @@ impl BaseDay for Day12 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
-        let areas = self.build_hash_map();
+        let areas = self.build_hash_map()?;
 
         for entry in areas {
             for area in entry.1 {
@@ -344,11 +342,11 @@ impl BaseDay for Day12 {
         Ok(result.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
-        let areas = self.build_hash_map();
+        let areas = self.build_hash_map()?;
 
-        let grid = Grid::from_vector(&self.read_file_into_vec_of_vec());
+        let grid = Grid::from_vector(&self.read_file_into_vec_of_vec()?);
         for entry in areas {
             for area in entry.1 {
                 result += self.calc_price_with_discount(&area, &grid);
@@ -358,7 +356,11 @@ impl BaseDay for Day12 {
         Ok(result.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
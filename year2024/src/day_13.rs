@@ -1,16 +1,15 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
-use std::error::Error;
-use std::path::PathBuf;
+use common::error::{AocError, AocResult};
+use common::file::{Input, get_input_path};
 
 pub struct Day13 {
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Day13 {
     pub fn new() -> Self {
         Day13 {
-            file_path: get_input_path(2024, 13),
+            input: get_input_path(2024, 13).into(),
         }
     }
 
@@ -90,14 +89,14 @@ impl BaseDay for Day13 {
         13
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
-        Ok(String::new())
+    fn part_1(&mut self) -> AocResult<String> {
+        Err(AocError::Unimplemented)
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         // Remove empty lines
         let lines: Vec<String> = self
-            .read_file_into_vec()
+            .read_file_into_vec()?
             .into_iter()
             .filter(|line| !line.trim().is_empty())
             .collect();
@@ -110,7 +109,7 @@ impl BaseDay for Day13 {
             } // skip incomplete chunks
 
             let parse = |s: &String| {
-                s.split(|c| c == '=' || c == '+' || c == ',')
+                s.split(['=', '+', ','])
                     .filter_map(|x| x.trim().parse::<i128>().ok())
                     .collect::<Vec<i128>>()
             };
@@ -140,7 +139,11 @@ impl BaseDay for Day13 {
         }
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
@@ -1,52 +1,63 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
-use itertools::Itertools;
-use std::error::Error;
-use std::path::PathBuf;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
+use common::grid::{Grid, Point};
+use common::params::DayParams;
+use common::parse::{ParseError, Pattern};
+use common::render::{self, Rgb};
+use log::{Level, debug, log_enabled, trace};
 
 #[derive(Debug, Clone)]
 struct Robot {
-    position: (i64, i64),
-    velocity: (i64, i64),
+    position: Point<i64>,
+    velocity: Point<i64>,
 }
 
 impl Robot {
-    fn from_input(input: &String) -> Robot {
-        let re = regex::Regex::new(r"p=(\d+),(\d+) v=(-?)(\d+),(-?)(\d+)").unwrap();
-        let captures = re.captures(input.as_str()).unwrap();
-        let pos_x = captures.get(1).unwrap().as_str().parse::<i64>().unwrap();
-        let pos_y = captures.get(2).unwrap().as_str().parse::<i64>().unwrap();
-        let vel_x_signal = captures.get(3).unwrap().as_str();
-        let mut vel_x = captures.get(4).unwrap().as_str().parse::<i64>().unwrap();
-        let vel_y_signal = captures.get(5).unwrap().as_str();
-        let mut vel_y = captures.get(6).unwrap().as_str().parse::<i64>().unwrap();
-
-        if vel_x_signal.len() == 1 {
-            vel_x = -vel_x;
-        }
+    fn from_input(input: &str) -> Result<Robot, ParseError> {
+        let (pos_x, pos_y, vel_x, vel_y) = Pattern::new("p={},{} v={},{}").parse(input)?;
 
-        if vel_y_signal.len() == 1 {
-            vel_y = -vel_y;
-        }
+        Ok(Robot {
+            position: Point::new(pos_y, pos_x),
+            velocity: Point::new(vel_y, vel_x),
+        })
+    }
+}
 
-        Robot {
-            position: (pos_y, pos_x),
-            velocity: (vel_y, vel_x),
-        }
+/// Empty tiles stay dark, tiles with robots are green.
+fn robot_colour(count: &u32) -> Rgb {
+    match count {
+        0 => [16, 16, 32],
+        _ => [40, 200, 60],
     }
 }
 
 pub struct Day14 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
+    /// Size of the room as (rows, cols).
+    grid_size: (i64, i64),
+    /// How long the robots move before part 1 counts them.
+    seconds: i64,
 }
 
 impl Day14 {
     pub fn new() -> Day14 {
         Day14 {
             day_number: 14,
-            file_path: get_input_path(2024, 14),
+            input: get_input_path(2024, 14).into(),
+            grid_size: (103, 101),
+            seconds: 7916,
+        }
+    }
+
+    fn robots(&self) -> AocResult<Vec<Robot>> {
+        let mut robots = Vec::new();
+        for (index, line) in self.input_lines()?.into_iter().enumerate() {
+            robots.push(Robot::from_input(line).map_err(|e| e.at_line(index + 1))?);
         }
+
+        Ok(robots)
     }
 
     fn move_robot_without_loop(
@@ -54,51 +65,36 @@ impl Day14 {
         robot: &Robot,
         number_of_steps: i64,
         grid_size: (i64, i64),
-    ) -> (i64, i64) {
-        let mut new_position = (
-            robot.position.0 + number_of_steps * robot.velocity.0,
-            robot.position.1 + number_of_steps * robot.velocity.1,
-        );
-
-        let rest_0 = new_position.0 % (grid_size.0);
-        let rest_1 = new_position.1 % (grid_size.1);
-
-        if rest_0 == 0 {
-            new_position.0 = rest_0;
-        } else if rest_0 > 0 {
-            new_position.0 = rest_0;
-        } else {
-            new_position.0 = grid_size.0 + new_position.0 % grid_size.0;
-        }
-
-        if rest_1 == 0 {
-            new_position.1 = rest_1;
-        } else if rest_1 > 0 {
-            new_position.1 = rest_1;
-        } else {
-            new_position.1 = grid_size.1 + new_position.1 % grid_size.1;
-        }
-
-        new_position
+    ) -> Point<i64> {
+        (robot.position + robot.velocity * number_of_steps).wrap(grid_size.0, grid_size.1)
     }
 
-    fn print_grid(&self, positions: &Vec<(i64, i64)>, grid_size: (i64, i64)) {
+    /// The room with the number of robots on each tile.
+    fn room(&self, positions: &[Point<i64>], grid_size: (i64, i64)) -> Grid<u32> {
+        let mut grid = Grid::new();
         for x in 0..grid_size.0 {
             for y in 0..grid_size.1 {
-                let count = positions.into_iter().filter(|c| **c == (x, y)).count();
-                if count > 0 {
-                    print!("{}", count);
-                } else {
-                    print!(".");
-                }
+                grid.insert(
+                    Point {
+                        x: x as i32,
+                        y: y as i32,
+                    },
+                    0,
+                );
             }
-            println!();
         }
+        for position in positions {
+            let point = Point::new(position.x as i32, position.y as i32);
+            let count = grid.get(point).copied().unwrap_or(0);
+            grid.update(point, count + 1);
+        }
+
+        grid
     }
 
     fn number_of_robots_per_quadrant(
         &self,
-        positions: &Vec<(i64, i64)>,
+        positions: &[Point<i64>],
         grid_size: (i64, i64),
     ) -> (u64, u64, u64, u64) {
         let mut first_quadrant = 0;
@@ -108,7 +104,7 @@ impl Day14 {
 
         for x in 0..grid_size.0 {
             for y in 0..grid_size.1 {
-                let count = positions.into_iter().filter(|c| **c == (x, y)).count();
+                let count = positions.iter().filter(|c| **c == Point::new(x, y)).count();
                 if x < grid_size.0 / 2 {
                     if y < grid_size.1 / 2 {
                         first_quadrant += count;
@@ -133,16 +129,13 @@ impl Day14 {
         )
     }
 
-    fn count_touches(&self, positions: &Vec<(i64, i64)>) -> u64 {
+    fn count_touches(&self, positions: &[Point<i64>]) -> u64 {
         let mut result = 0;
 
-        for p in positions {
-            if positions.into_iter().contains(&(p.0 + 1, p.1))
-                || positions.into_iter().contains(&(p.0, p.1 + 1))
-                || positions.into_iter().contains(&(p.0 + 1, p.1 + 1))
-                || positions.into_iter().contains(&(p.0 - 1, p.1))
-                || positions.into_iter().contains(&(p.0, p.1 - 1))
-                || positions.into_iter().contains(&(p.0 - 1, p.1 - 1))
+        for &p in positions {
+            if [(1, 0), (0, 1), (1, 1), (-1, 0), (0, -1), (-1, -1)]
+                .into_iter()
+                .any(|offset| positions.contains(&(p + Point::from(offset))))
             {
                 result += 1;
             }
@@ -157,52 +150,86 @@ impl BaseDay for Day14 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
-        let grid_size = (103, 101);
-        let count = 7916;
+    fn part_1(&mut self) -> AocResult<String> {
+        let grid_size = self.grid_size;
+        let count = self.seconds;
         let mut final_positions = Vec::new();
 
-        let input = self.read_file_into_vec();
-        for line in input {
-            let robot = Robot::from_input(&line);
+        for robot in self.robots()? {
             let final_position = self.move_robot_without_loop(&robot, count, grid_size);
             final_positions.push(final_position);
         }
 
-        self.print_grid(&final_positions, grid_size);
+        if log_enabled!(Level::Debug) {
+            let room = self.room(&final_positions, grid_size);
+            debug!("\n{}", render::to_ansi(&room, robot_colour));
+        }
 
         let counts = self.number_of_robots_per_quadrant(&final_positions, grid_size);
         Ok((counts.0 * counts.1 * counts.2 * counts.3).to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
-        let grid_size = (103, 101);
+    fn part_2(&mut self) -> AocResult<String> {
+        let grid_size = self.grid_size;
         let mut count = 6_098;
         let mut touches = Vec::new();
         let mut max_touches = (0, 0);
+        let robots = self.robots()?;
 
         while count <= 8_000 {
             let mut final_positions = Vec::new();
-            let input = self.read_file_into_vec();
-            for line in input {
-                let robot = Robot::from_input(&line);
-                let final_position = self.move_robot_without_loop(&robot, count, grid_size);
+            for robot in &robots {
+                let final_position = self.move_robot_without_loop(robot, count, grid_size);
                 final_positions.push(final_position);
             }
 
             let t = self.count_touches(&final_positions);
             if t > max_touches.1 {
                 max_touches = (count, t);
-                println!("max_touches: {:?}", max_touches);
+                debug!("max_touches: {:?}", max_touches);
+                if log_enabled!(Level::Trace) {
+                    let room = self.room(&final_positions, grid_size);
+                    trace!("\n{}", render::to_ansi(&room, robot_colour));
+                }
             }
             touches.push(t);
-            count += 101;
+            count += grid_size.1;
         }
-        println!("max_touches: {:?}", max_touches);
+        debug!("max_touches: {:?}", max_touches);
         Ok(count.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn apply_params(&mut self, params: &DayParams) -> AocResult<()> {
+        self.grid_size = (
+            params.get_or("rows", self.grid_size.0)?,
+            params.get_or("cols", self.grid_size.1)?,
+        );
+        self.seconds = params.get_or("seconds", self.seconds)?;
+        Ok(())
+    }
+
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::error::AocError;
+    use common::file::InputSource;
+
+    #[test]
+    fn parse_error_line_test() {
+        let mut day = Day14::new().with_input(InputSource::Text(String::from(
+            "p=0,4 v=3,-3\np=6,3 v=-1\n",
+        )));
+
+        let error = day.part_1().unwrap_err();
+        assert!(matches!(error, AocError::Parse { line: Some(2), .. }));
     }
 }
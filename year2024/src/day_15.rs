@@ -1,21 +1,20 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::grid::{Grid, Point};
 use log::debug;
 use std::collections::HashMap;
-use std::error::Error;
-use std::path::PathBuf;
 
 pub struct Day15 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Day15 {
     pub fn new() -> Day15 {
         Day15 {
             day_number: 15,
-            file_path: get_input_path(2024, 15),
+            input: get_input_path(2024, 15).into(),
         }
     }
 
@@ -25,7 +24,7 @@ impl Day15 {
 
         for line in input {
             if !line.is_empty() {
-                if *line.get(0).unwrap() == '#' {
+                if *line.first().unwrap() == '#' {
                     // Map
                     map.push(line);
                 } else {
@@ -74,11 +73,9 @@ impl Day15 {
                 debug!("Hit a wall at {:?}, not moving!", position);
                 return HashMap::new();
             }
-        } else if value == ']' {
-            if walls.contains(&position.add(0, -1)) {
-                debug!("Hit a wall at {:?}, not moving!", position);
-                return HashMap::new();
-            }
+        } else if value == ']' && walls.contains(&position.add(0, -1)) {
+            debug!("Hit a wall at {:?}, not moving!", position);
+            return HashMap::new();
         }
 
         if value == 'O' {
@@ -169,91 +166,13 @@ impl Day15 {
     }
 
     fn duplicate_width(&self, grid: &Grid<char>) -> Grid<char> {
-        let mut adjusted_grid = Grid::new();
-
-        for row in 0..grid.rows() {
-            let mut adjusted_col = 0;
-            for col in 0..grid.cols() {
-                let value = *grid.get(Point { x: row, y: col }).unwrap();
-                match value {
-                    '#' => {
-                        adjusted_grid.insert(
-                            Point {
-                                x: row,
-                                y: adjusted_col,
-                            },
-                            '#',
-                        );
-                        adjusted_col += 1;
-                        adjusted_grid.insert(
-                            Point {
-                                x: row,
-                                y: adjusted_col,
-                            },
-                            '#',
-                        );
-                        adjusted_col += 1;
-                    }
-                    'O' => {
-                        adjusted_grid.insert(
-                            Point {
-                                x: row,
-                                y: adjusted_col,
-                            },
-                            '[',
-                        );
-                        adjusted_col += 1;
-                        adjusted_grid.insert(
-                            Point {
-                                x: row,
-                                y: adjusted_col,
-                            },
-                            ']',
-                        );
-                        adjusted_col += 1;
-                    }
-                    '.' => {
-                        adjusted_grid.insert(
-                            Point {
-                                x: row,
-                                y: adjusted_col,
-                            },
-                            '.',
-                        );
-                        adjusted_col += 1;
-                        adjusted_grid.insert(
-                            Point {
-                                x: row,
-                                y: adjusted_col,
-                            },
-                            '.',
-                        );
-                        adjusted_col += 1;
-                    }
-                    '@' => {
-                        adjusted_grid.insert(
-                            Point {
-                                x: row,
-                                y: adjusted_col,
-                            },
-                            '@',
-                        );
-                        adjusted_col += 1;
-                        adjusted_grid.insert(
-                            Point {
-                                x: row,
-                                y: adjusted_col,
-                            },
-                            '.',
-                        );
-                        adjusted_col += 1;
-                    }
-                    _ => unreachable!(),
-                }
-            }
-        }
-
-        adjusted_grid
+        grid.widen(|value| match value {
+            '#' => vec!['#', '#'],
+            'O' => vec!['[', ']'],
+            '.' => vec!['.', '.'],
+            '@' => vec!['@', '.'],
+            _ => unreachable!(),
+        })
     }
 
     fn sum_of_gps_coordinates(&self, grid: &Grid<char>, box_identifier: char) -> u64 {
@@ -270,7 +189,7 @@ impl Day15 {
         box_identifier: char,
     ) -> u64 {
         let mut position = *grid.find('@').unwrap().0;
-        let walls = grid.filter('#').iter().map(|x| *x).collect::<Vec<Point>>();
+        let walls = grid.filter('#').to_vec();
 
         for instruction in instructions {
             let operations = self.move_object_2(grid, position, instruction, &walls);
@@ -283,7 +202,7 @@ impl Day15 {
             grid.print();
         }
 
-        self.sum_of_gps_coordinates(&grid, box_identifier)
+        self.sum_of_gps_coordinates(grid, box_identifier)
     }
 }
 
@@ -292,16 +211,16 @@ impl BaseDay for Day15 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
-        let input = self.read_file_into_vec_of_vec();
+    fn part_1(&mut self) -> AocResult<String> {
+        let input = self.read_file_into_vec_of_vec()?;
         let (mut grid, instructions) = self.read_input(input);
         Ok(self
             .follow_instructions(&mut grid, instructions, 'O')
             .to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
-        let input = self.read_file_into_vec_of_vec();
+    fn part_2(&mut self) -> AocResult<String> {
+        let input = self.read_file_into_vec_of_vec()?;
         let (_grid, instructions) = self.read_input(input);
         let mut adjusted_grid = self.duplicate_width(&_grid);
 
@@ -310,8 +229,12 @@ impl BaseDay for Day15 {
             .to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -331,8 +254,7 @@ mod tests {
         init_logger();
 
         let expected = "10092";
-        let mut day = Day15::new();
-        day.file_path = PathBuf::from("./data/day_15/big_example.txt");
+        let mut day = Day15::new().with_input_path("./data/day_15/big_example.txt");
 
         let result = day.part_1();
         assert_eq!(expected, result.unwrap());
@@ -343,8 +265,7 @@ mod tests {
         init_logger();
 
         let expected = "2028";
-        let mut day = Day15::new();
-        day.file_path = PathBuf::from("./data/day_15/small_example.txt");
+        let mut day = Day15::new().with_input_path("./data/day_15/small_example.txt");
 
         let result = day.part_1();
         assert_eq!(expected, result.unwrap());
@@ -355,8 +276,7 @@ mod tests {
         init_logger();
 
         let expected = "618";
-        let mut day = Day15::new();
-        day.file_path = PathBuf::from("./data/day_15/small_example_part_2.txt");
+        let mut day = Day15::new().with_input_path("./data/day_15/small_example_part_2.txt");
 
         let result = day.part_2();
         assert_eq!(expected, result.unwrap());
@@ -367,8 +287,7 @@ mod tests {
         init_logger();
 
         let expected = "406";
-        let mut day = Day15::new();
-        day.file_path = PathBuf::from("./data/day_15/small_example_part_2_1.txt");
+        let mut day = Day15::new().with_input_path("./data/day_15/small_example_part_2_1.txt");
 
         let result = day.part_2();
         assert_eq!(expected, result.unwrap());
@@ -379,8 +298,7 @@ mod tests {
         init_logger();
 
         let expected = "509";
-        let mut day = Day15::new();
-        day.file_path = PathBuf::from("./data/day_15/small_example_part_2_2.txt");
+        let mut day = Day15::new().with_input_path("./data/day_15/small_example_part_2_2.txt");
 
         let result = day.part_2();
         assert_eq!(expected, result.unwrap());
@@ -391,8 +309,7 @@ mod tests {
         init_logger();
 
         let expected = "9021";
-        let mut day = Day15::new();
-        day.file_path = PathBuf::from("./data/day_15/big_example.txt");
+        let mut day = Day15::new().with_input_path("./data/day_15/big_example.txt");
 
         let result = day.part_2();
         assert_eq!(expected, result.unwrap());
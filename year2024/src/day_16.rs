@@ -1,19 +1,26 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
-use common::graph::Graph;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::grid::{Grid, Point};
-use common::utils::{add_corners, add_edges_to_graph, rebuild_path_counting_nodes};
-use std::error::Error;
-use std::path::PathBuf;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
 
 const START_SYMBOL: char = 'S';
 const END_SYMBOL: char = 'E';
-const NODE_SYMBOL: char = '.';
 const WALL_SYMBOL: char = '#';
 
+const MOVE_COST: u64 = 1;
+const TURN_COST: u64 = 1_000;
+
+/// East, south, west, north; turning clockwise moves one index forward.
+const DIRECTIONS: [(i32, i32); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+const EAST: usize = 0;
+
+type State = (Point, usize);
+
 pub struct Day16 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
     part_1_result: Option<u64>,
     part_2_result: Option<u64>,
 }
@@ -22,31 +29,88 @@ impl Day16 {
     pub fn new() -> Day16 {
         Day16 {
             day_number: 16,
-            file_path: get_input_path(2024, 16),
+            input: get_input_path(2024, 16).into(),
             part_1_result: None,
             part_2_result: None,
         }
     }
 
-    fn run(&self) -> (u64, u64) {
-        let input = self.read_file_into_vec_of_vec();
-        let grid = Grid::from_vector(&input);
+    fn shortest_distances(
+        grid: &Grid<char>,
+        starts: &[State],
+        backwards: bool,
+    ) -> HashMap<State, u64> {
+        let mut distances: HashMap<State, u64> = HashMap::new();
+        let mut heap = BinaryHeap::new();
+
+        for &start in starts {
+            distances.insert(start, 0);
+            heap.push((Reverse(0u64), start));
+        }
 
-        let starting_point = *grid.find(START_SYMBOL).unwrap().0;
-        let finishing_point = *grid.find(END_SYMBOL).unwrap().0;
+        while let Some((Reverse(cost), (point, direction))) = heap.pop() {
+            if distances
+                .get(&(point, direction))
+                .is_some_and(|&d| cost > d)
+            {
+                continue;
+            }
+
+            let (dx, dy) = DIRECTIONS[direction];
+            let next_point = if backwards {
+                point.add(-dx, -dy)
+            } else {
+                point.add(dx, dy)
+            };
+
+            let mut next_states = vec![
+                ((point, (direction + 1) % 4), cost + TURN_COST),
+                ((point, (direction + 3) % 4), cost + TURN_COST),
+            ];
+            if grid.get(next_point).is_some_and(|c| *c != WALL_SYMBOL) {
+                next_states.push(((next_point, direction), cost + MOVE_COST));
+            }
+
+            for (state, new_cost) in next_states {
+                if distances.get(&state).is_none_or(|&d| new_cost < d) {
+                    distances.insert(state, new_cost);
+                    heap.push((Reverse(new_cost), state));
+                }
+            }
+        }
 
-        let mut graph: Graph<Point> = Graph::new();
+        distances
+    }
 
-        let start_node = graph.add_node(starting_point);
-        let end_node = graph.add_node(finishing_point);
-        add_corners(&grid, &mut graph, NODE_SYMBOL);
-        add_edges_to_graph(&grid, &mut graph, WALL_SYMBOL);
-        let (result, predecessors) = graph.dijkstra(start_node);
+    fn run(&self) -> AocResult<(u64, u64)> {
+        let input = self.read_file_into_vec_of_vec()?;
+        let grid = Grid::from_vector(&input);
 
-        let unique_points =
-            rebuild_path_counting_nodes(&graph, &predecessors, start_node, end_node);
+        let starting_point = *grid.find(START_SYMBOL).unwrap().0;
+        let finishing_point = *grid.find(END_SYMBOL).unwrap().0;
 
-        (*result.get(&end_node).unwrap(), unique_points.len() as u64)
+        let from_start = Self::shortest_distances(&grid, &[(starting_point, EAST)], false);
+        let end_states = (0..DIRECTIONS.len())
+            .map(|direction| (finishing_point, direction))
+            .collect::<Vec<_>>();
+        let from_end = Self::shortest_distances(&grid, &end_states, true);
+
+        let best = end_states
+            .iter()
+            .filter_map(|state| from_start.get(state))
+            .min()
+            .copied()
+            .unwrap();
+
+        let mut best_tiles = from_start
+            .iter()
+            .filter(|(state, cost)| from_end.get(state).is_some_and(|c| *c + **cost == best))
+            .map(|((point, _), _)| *point)
+            .collect::<Vec<_>>();
+        best_tiles.sort();
+        best_tiles.dedup();
+
+        Ok((best, best_tiles.len() as u64))
     }
 }
 
@@ -55,22 +119,34 @@ impl BaseDay for Day16 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
+        if self.part_1_result.is_none() {
+            self.setup()?;
+        }
         Ok(self.part_1_result.unwrap().to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
+        if self.part_2_result.is_none() {
+            self.setup()?;
+        }
         Ok(self.part_2_result.unwrap().to_string())
     }
 
-    fn setup(&mut self) -> () {
-        let (part_1, part_2) = self.run();
+    fn setup(&mut self) -> AocResult<()> {
+        let (part_1, part_2) = self.run()?;
         self.part_1_result = Some(part_1);
         self.part_2_result = Some(part_2);
+
+        Ok(())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -88,31 +164,28 @@ mod tests {
     #[test]
     fn part_1_example_1_test() {
         init_logger();
-        let _path = "./data/day_16/example_1.txt";
         let expected = (7036, 45);
-        let day = Day16::new();
+        let day = Day16::new().with_input_path("./data/day_16/example_1.txt");
         let result = day.run();
 
-        assert_eq!(expected, result);
+        assert_eq!(expected, result.unwrap());
     }
 
     #[test]
     fn part_1_example_2_test() {
         init_logger();
-        let _path = "./data/day_16/example_2.txt";
         let expected = (11048, 64);
-        let day = Day16::new();
+        let day = Day16::new().with_input_path("./data/day_16/example_2.txt");
         let result = day.run();
 
-        assert_eq!(expected, result);
+        assert_eq!(expected, result.unwrap());
     }
 
     #[test]
     fn part_1_example_3_test() {
         init_logger();
-        let _path = "./data/day_16/example_3.txt";
         let expected = "1006";
-        let mut day = Day16::new();
+        let mut day = Day16::new().with_input_path("./data/day_16/example_3.txt");
         let result = day.part_1();
 
         assert_eq!(expected, result.unwrap());
@@ -121,9 +194,8 @@ mod tests {
     #[test]
     fn part_1_example_4_test() {
         init_logger();
-        let _path = "./data/day_16/example_4.txt";
         let expected = "1004";
-        let mut day = Day16::new();
+        let mut day = Day16::new().with_input_path("./data/day_16/example_4.txt");
         let result = day.part_1();
 
         assert_eq!(expected, result.unwrap());
@@ -0,0 +1,429 @@
+use common::error::{AocError, AocResult};
+use common::parse::{blocks, ints};
+use std::collections::{BTreeSet, HashSet};
+use std::fmt::{Display, Formatter};
+
+/// Instructions a single run may execute before it is treated as an endless loop.
+pub const STEP_LIMIT: u64 = 10_000_000;
+
+#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug)]
+pub enum Opcode {
+    /// `a = a >> combo`
+    Adv,
+    /// `b = b ^ literal`
+    Bxl,
+    /// `b = combo % 8`
+    Bst,
+    /// `if a != 0 { ip = literal }`
+    Jnz,
+    /// `b = b ^ c`, the operand is ignored
+    Bxc,
+    /// `out(combo % 8)`
+    Out,
+    /// `b = a >> combo`
+    Bdv,
+    /// `c = a >> combo`
+    Cdv,
+}
+
+impl Opcode {
+    pub fn from_code(code: u8) -> AocResult<Opcode> {
+        Ok(match code {
+            0 => Opcode::Adv,
+            1 => Opcode::Bxl,
+            2 => Opcode::Bst,
+            3 => Opcode::Jnz,
+            4 => Opcode::Bxc,
+            5 => Opcode::Out,
+            6 => Opcode::Bdv,
+            7 => Opcode::Cdv,
+            _ => return Err(AocError::parse(format!("{} is not a 3-bit opcode", code))),
+        })
+    }
+
+    pub fn mnemonic(&self) -> &'static str {
+        match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        }
+    }
+
+    fn takes_combo(&self) -> bool {
+        matches!(
+            self,
+            Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv
+        )
+    }
+}
+
+/// One decoded instruction, printed as e.g. `adv 3` or `out b`.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub struct Instruction {
+    pub opcode: Opcode,
+    pub operand: u8,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let operand = match (self.opcode.takes_combo(), self.operand) {
+            (true, 4) => String::from("a"),
+            (true, 5) => String::from("b"),
+            (true, 6) => String::from("c"),
+            (true, 7) => String::from("<reserved>"),
+            (false, _) if self.opcode == Opcode::Bxc => return write!(f, "bxc"),
+            (_, operand) => operand.to_string(),
+        };
+        write!(f, "{} {}", self.opcode.mnemonic(), operand)
+    }
+}
+
+/// The listing of a program, one `ip: instruction` per line.
+pub fn disassemble(program: &[u8]) -> AocResult<String> {
+    let mut listing = String::new();
+    for (index, pair) in program.chunks(2).enumerate() {
+        let instruction = Instruction {
+            opcode: Opcode::from_code(pair[0])?,
+            operand: *pair.get(1).unwrap_or(&0),
+        };
+        listing.push_str(&format!("{:02}: {}\n", index * 2, instruction));
+    }
+
+    Ok(listing)
+}
+
+#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug, Default)]
+pub struct Registers {
+    pub a: u64,
+    pub b: u64,
+    pub c: u64,
+}
+
+/// What a single step did.
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Step {
+    Executed,
+    Output(u8),
+    Halted,
+}
+
+#[derive(Eq, PartialEq, Clone, Copy, Debug)]
+pub enum Stop {
+    Halted,
+    /// Execution paused before the instruction at this address.
+    Breakpoint(usize),
+}
+
+/// One executed instruction and the registers it left behind.
+#[derive(Eq, PartialEq, Clone, Debug)]
+pub struct TraceEntry {
+    pub ip: usize,
+    pub instruction: Instruction,
+    pub registers: Registers,
+    pub output: Option<u8>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Machine {
+    pub registers: Registers,
+    pub ip: usize,
+    pub output: Vec<u8>,
+    program: Vec<u8>,
+    breakpoints: BTreeSet<usize>,
+    steps: u64,
+}
+
+/// `a >> shift`, which is `a / 2^shift` without overflowing for large shifts.
+fn shift(a: u64, shift: u64) -> u64 {
+    u32::try_from(shift)
+        .ok()
+        .and_then(|shift| a.checked_shr(shift))
+        .unwrap_or(0)
+}
+
+impl Machine {
+    pub fn new(program: Vec<u8>, registers: Registers) -> Machine {
+        Machine {
+            registers,
+            ip: 0,
+            output: Vec::new(),
+            program,
+            breakpoints: BTreeSet::new(),
+            steps: 0,
+        }
+    }
+
+    /// Reads the `Register X: n` lines and the `Program:` line of the puzzle input.
+    pub fn parse(input: &str) -> AocResult<Machine> {
+        let sections = blocks(input);
+        let [registers, program] = sections.as_slice() else {
+            return Err(AocError::parse("expected registers and a program"));
+        };
+
+        let [a, b, c] = ints::<u64>(registers)?[..] else {
+            return Err(AocError::parse("expected three registers"));
+        };
+        let program = ints::<u8>(program)?;
+        for &code in &program {
+            Opcode::from_code(code)?;
+        }
+
+        Ok(Machine::new(program, Registers { a, b, c }))
+    }
+
+    pub fn program(&self) -> &[u8] {
+        &self.program
+    }
+
+    /// Pauses [`Machine::run`] before the instruction at `ip`, see the
+    /// `breakpoint` parameter of day 17.
+    pub fn add_breakpoint(&mut self, ip: usize) {
+        self.breakpoints.insert(ip);
+    }
+
+    /// Starts over with other registers, keeping the program and breakpoints.
+    pub fn reset(&mut self, registers: Registers) {
+        self.registers = registers;
+        self.ip = 0;
+        self.output.clear();
+        self.steps = 0;
+    }
+
+    pub fn output_string(&self) -> String {
+        self.output
+            .iter()
+            .map(u8::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn combo(&self, operand: u8) -> AocResult<u64> {
+        match operand {
+            0..=3 => Ok(operand as u64),
+            4 => Ok(self.registers.a),
+            5 => Ok(self.registers.b),
+            6 => Ok(self.registers.c),
+            _ => Err(AocError::other(format!(
+                "reserved combo operand {} at {}",
+                operand, self.ip
+            ))),
+        }
+    }
+
+    fn current(&self) -> Option<Instruction> {
+        let opcode = Opcode::from_code(*self.program.get(self.ip)?).ok()?;
+        let operand = *self.program.get(self.ip + 1)?;
+        Some(Instruction { opcode, operand })
+    }
+
+    /// Executes one instruction. Fails once [`STEP_LIMIT`] instructions ran
+    /// since the last reset, as the program would then most likely never halt.
+    pub fn step(&mut self) -> AocResult<Step> {
+        let Some(Instruction { opcode, operand }) = self.current() else {
+            return Ok(Step::Halted);
+        };
+        if self.steps == STEP_LIMIT {
+            return Err(AocError::other(format!(
+                "no halt after {} instructions, stopped at {}",
+                STEP_LIMIT, self.ip
+            )));
+        }
+        self.steps += 1;
+        let value = if opcode.takes_combo() {
+            self.combo(operand)?
+        } else {
+            operand as u64
+        };
+        self.ip += 2;
+
+        let registers = &mut self.registers;
+        let mut step = Step::Executed;
+        match opcode {
+            Opcode::Adv => registers.a = shift(registers.a, value),
+            Opcode::Bxl => registers.b ^= value,
+            Opcode::Bst => registers.b = value % 8,
+            Opcode::Jnz if registers.a != 0 => self.ip = value as usize,
+            Opcode::Jnz => {}
+            Opcode::Bxc => registers.b ^= registers.c,
+            Opcode::Out => {
+                let value = (value % 8) as u8;
+                self.output.push(value);
+                step = Step::Output(value);
+            }
+            Opcode::Bdv => registers.b = shift(registers.a, value),
+            Opcode::Cdv => registers.c = shift(registers.a, value),
+        }
+
+        Ok(step)
+    }
+
+    /// Runs until the program halts or reaches a breakpoint. The instruction
+    /// the machine is paused on always runs, so calling this again resumes.
+    pub fn run(&mut self) -> AocResult<Stop> {
+        loop {
+            if self.step()? == Step::Halted {
+                return Ok(Stop::Halted);
+            }
+            if self.breakpoints.contains(&self.ip) {
+                return Ok(Stop::Breakpoint(self.ip));
+            }
+        }
+    }
+
+    /// Runs to the end, recording every instruction, for printing alongside
+    /// the [`disassemble`] listing.
+    pub fn trace(&mut self) -> AocResult<Vec<TraceEntry>> {
+        let mut trace = Vec::new();
+        while let Some(instruction) = self.current() {
+            let ip = self.ip;
+            let output = match self.step()? {
+                Step::Output(value) => Some(value),
+                _ => None,
+            };
+            trace.push(TraceEntry {
+                ip,
+                instruction,
+                registers: self.registers,
+                output,
+            });
+        }
+
+        Ok(trace)
+    }
+}
+
+/// The smallest value of register A that makes the program print itself.
+///
+/// Quines of this machine consume A three bits per loop and each output only
+/// depends on the bits left in A, so the last output is fixed by the highest
+/// octal digit. Digits are therefore searched from the most significant one,
+/// keeping every prefix whose run reproduces the tail of the program.
+pub fn solve_quine(machine: &Machine) -> AocResult<u64> {
+    let program = machine.program().to_vec();
+    let initial = machine.registers;
+    let mut machine = machine.clone();
+    machine.breakpoints.clear();
+    let mut prefixes: HashSet<u64> = HashSet::from([0]);
+
+    for start in (0..program.len()).rev() {
+        let mut next = HashSet::new();
+        for prefix in prefixes {
+            for digit in 0..8 {
+                let a = prefix * 8 + digit;
+                machine.reset(Registers { a, ..initial });
+                machine.run()?;
+                if machine.output == program[start..] {
+                    next.insert(a);
+                }
+            }
+        }
+        prefixes = next;
+    }
+
+    prefixes
+        .into_iter()
+        .filter(|&a| a != 0)
+        .min()
+        .ok_or(AocError::NoSolution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(a: u64, b: u64, c: u64, program: Vec<u8>) -> AocResult<Machine> {
+        let mut machine = Machine::new(program, Registers { a, b, c });
+        machine.run()?;
+        Ok(machine)
+    }
+
+    #[test]
+    fn instructions_test() -> AocResult<()> {
+        assert_eq!(run(0, 0, 9, vec![2, 6])?.registers.b, 1);
+        assert_eq!(
+            run(10, 0, 0, vec![5, 0, 5, 1, 5, 4])?.output_string(),
+            "0,1,2"
+        );
+        assert_eq!(run(0, 29, 0, vec![1, 7])?.registers.b, 26);
+        assert_eq!(run(0, 2024, 43690, vec![4, 0])?.registers.b, 44354);
+
+        let machine = run(2024, 0, 0, vec![0, 1, 5, 4, 3, 0])?;
+        assert_eq!(machine.output_string(), "4,2,5,6,7,7,7,7,3,1,0");
+        assert_eq!(machine.registers.a, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn large_registers_test() -> AocResult<()> {
+        // Shifts past 32 bits overflowed the old u32 registers.
+        let machine = run(1 << 40, 40, 0, vec![0, 5, 5, 4])?;
+        assert_eq!(machine.registers.a, 1);
+        assert_eq!(run(u64::MAX, 70, 0, vec![6, 5])?.registers.b, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn reserved_operand_test() {
+        assert!(run(0, 0, 0, vec![5, 7]).is_err());
+    }
+
+    #[test]
+    fn step_limit_test() {
+        // `jnz 0` with a non-zero A jumps back to itself forever.
+        let error = run(1, 0, 0, vec![3, 0]).unwrap_err();
+        assert!(error.to_string().contains("no halt"));
+    }
+
+    #[test]
+    fn disassemble_test() -> AocResult<()> {
+        assert_eq!(
+            disassemble(&[2, 4, 1, 3, 7, 5, 4, 1, 5, 5, 3, 0])?,
+            "00: bst a\n02: bxl 3\n04: cdv b\n06: bxc\n08: out b\n10: jnz 0\n"
+        );
+        assert!(disassemble(&[8, 0]).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn breakpoint_and_trace_test() -> AocResult<()> {
+        let mut machine = Machine::new(vec![0, 1, 5, 4, 3, 0], Registers { a: 4, b: 0, c: 0 });
+        machine.add_breakpoint(4);
+
+        assert_eq!(machine.run()?, Stop::Breakpoint(4));
+        assert_eq!(machine.output, vec![2]);
+        assert_eq!(machine.run()?, Stop::Breakpoint(4));
+        assert_eq!(machine.run()?, Stop::Breakpoint(4));
+        assert_eq!(machine.run()?, Stop::Halted);
+        assert_eq!(machine.output_string(), "2,1,0");
+
+        machine.reset(Registers { a: 2, b: 0, c: 0 });
+        let trace = machine.trace()?;
+        assert_eq!(trace.len(), 6);
+        assert_eq!(trace[1].output, Some(1));
+        assert_eq!(trace[2].instruction.to_string(), "jnz 0");
+        assert_eq!(trace[5].registers.a, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn quine_test() -> AocResult<()> {
+        let mut machine = Machine::new(vec![0, 3, 5, 4, 3, 0], Registers::default());
+        machine.add_breakpoint(2);
+        let a = solve_quine(&machine)?;
+        assert_eq!(a, 117440);
+
+        let machine = run(a, 0, 0, vec![0, 3, 5, 4, 3, 0])?;
+        assert_eq!(machine.output, vec![0, 3, 5, 4, 3, 0]);
+
+        Ok(())
+    }
+}
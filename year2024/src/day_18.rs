@@ -1,18 +1,20 @@
 use common::base_day::BaseDay;
 use common::dsu::DSU;
-use common::file::get_input_path;
-use common::graph::Graph;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
+use common::graph::{Graph, shortest_path};
 use common::grid::{Grid, Point};
+use common::params::DayParams;
+use common::parse::{ParseError, Pattern};
 use common::utils::{add_corners, add_edges_to_graph};
-use std::error::Error;
-use std::path::PathBuf;
+use log::{Level, log_enabled, trace};
 
 const NODE_SYMBOL: char = '.';
 const WALL_SYMBOL: char = '#';
 
 pub struct Day18 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
     grid_size: i32,
     bytes_to_consume: usize,
 }
@@ -21,19 +23,15 @@ impl Day18 {
     pub fn new() -> Day18 {
         Day18 {
             day_number: 18,
-            file_path: get_input_path(2024, 18),
+            input: get_input_path(2024, 18).into(),
             grid_size: 70,
             bytes_to_consume: 1_025,
         }
     }
 
-    fn split_input(&self, line: &String) -> (i32, i32) {
-        let _s = line
-            .split(',')
-            .map(|x| x.parse::<i32>().unwrap())
-            .rev()
-            .collect::<Vec<i32>>();
-        (_s[0], _s[1])
+    fn split_input(&self, line: &str) -> Result<Point, ParseError> {
+        let (y, x) = Pattern::new("{},{}").parse(line)?;
+        Ok(Point::new(x, y))
     }
 }
 
@@ -42,8 +40,8 @@ impl BaseDay for Day18 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
-        let input = self.read_file_into_vec();
+    fn part_1(&mut self) -> AocResult<String> {
+        let input = self.read_file_into_vec()?;
         let mut grid = Grid::new();
 
         for x in 0..=self.grid_size {
@@ -52,9 +50,9 @@ impl BaseDay for Day18 {
             }
         }
 
-        for line in &input[0..self.bytes_to_consume] {
-            let (x, y) = self.split_input(&line);
-            grid.insert(Point { x, y }, '#');
+        for (index, line) in input[0..self.bytes_to_consume].iter().enumerate() {
+            let wall = self.split_input(line).map_err(|e| e.at_line(index + 1))?;
+            grid.insert(wall, '#');
         }
 
         let mut graph = Graph::new();
@@ -67,13 +65,21 @@ impl BaseDay for Day18 {
 
         add_corners(&grid, &mut graph, NODE_SYMBOL);
         add_edges_to_graph(&grid, &mut graph, WALL_SYMBOL);
-        let (result, _) = graph.dijkstra(start_node);
+        let (result, predecessors) = graph.dijkstra(start_node);
+
+        if log_enabled!(Level::Trace) {
+            let path = shortest_path(&predecessors, start_node, end_node).unwrap_or_default();
+            trace!(
+                "{}",
+                graph.to_dot_with(|p| format!("{},{}", p.x, p.y), &path)
+            );
+        }
 
         Ok(result.get(&end_node).unwrap().to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
-        let input = self.read_file_into_vec();
+    fn part_2(&mut self) -> AocResult<String> {
+        let input = self.read_file_into_vec()?;
         let mut walls = Vec::new();
         let mut nodes = vec![];
         let start_point = Point { x: 0, y: 0 };
@@ -82,9 +88,8 @@ impl BaseDay for Day18 {
             y: self.grid_size,
         };
 
-        for line in &input {
-            let (x, y) = self.split_input(&line);
-            walls.push(Point { x, y });
+        for (index, line) in input.iter().enumerate() {
+            walls.push(self.split_input(line).map_err(|e| e.at_line(index + 1))?);
         }
 
         for x in 0..=self.grid_size {
@@ -102,7 +107,7 @@ impl BaseDay for Day18 {
                 if dsu.find(current_node).is_none() {
                     continue;
                 }
-                for diffs in vec![(0, 1), (1, 0)] {
+                for diffs in [(0, 1), (1, 0)] {
                     let other = Point {
                         x: x + diffs.0,
                         y: y + diffs.1,
@@ -122,7 +127,7 @@ impl BaseDay for Day18 {
                     if dsu.find(current_node).is_none() {
                         continue;
                     }
-                    for diffs in vec![(0, 1), (1, 0)] {
+                    for diffs in [(0, 1), (1, 0)] {
                         let other = Point {
                             x: x + diffs.0,
                             y: y + diffs.1,
@@ -143,61 +148,17 @@ impl BaseDay for Day18 {
         Ok(String::new())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn apply_params(&mut self, params: &DayParams) -> AocResult<()> {
+        self.grid_size = params.get_or("grid_size", self.grid_size)?;
+        self.bytes_to_consume = params.get_or("bytes_to_consume", self.bytes_to_consume)?;
+        Ok(())
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    fn init_logger() {
-        let _ = env_logger::builder()
-            .filter_level(log::LevelFilter::Debug)
-            .is_test(true)
-            .try_init();
-    }
-
-    #[test]
-    fn part_1_test() {
-        init_logger();
-        let expected = "22";
-
-        let mut day = Day18::new();
-        day.grid_size = 6;
-        day.bytes_to_consume = 12;
-        let _path = "./data/day_18/example_1.txt";
-        let result = day.part_1();
 
-        assert_eq!(expected, result.unwrap());
+    fn input(&self) -> &Input {
+        &self.input
     }
 
-    #[test]
-    fn part_2_test() {
-        init_logger();
-        let expected = "6,1";
-
-        let mut day = Day18::new();
-        day.grid_size = 6;
-        day.bytes_to_consume = 12;
-        let _path = "./data/day_18/example_1.txt";
-        let result = day.part_2();
-
-        assert_eq!(expected, result.unwrap());
-    }
-
-    #[test]
-    fn part_2_smaller_test() {
-        init_logger();
-        let expected = "0,1";
-
-        let mut day = Day18::new();
-        day.grid_size = 2;
-        day.bytes_to_consume = 12;
-        let _path = "./data/day_18/example_2.txt";
-        let result = day.part_2();
-
-        assert_eq!(expected, result.unwrap());
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
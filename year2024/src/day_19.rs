@@ -1,13 +1,12 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use itertools::Itertools;
 use std::collections::{HashMap, HashSet};
-use std::error::Error;
-use std::path::PathBuf;
 
 pub struct Day19 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
     data_bool: HashMap<String, bool>,
     data_count: HashMap<String, u64>,
 }
@@ -16,7 +15,7 @@ impl Day19 {
     pub fn new() -> Day19 {
         Day19 {
             day_number: 19,
-            file_path: get_input_path(2024, 19),
+            input: get_input_path(2024, 19).into(),
             data_bool: HashMap::new(),
             data_count: HashMap::new(),
         }
@@ -26,7 +25,7 @@ impl Day19 {
         &self,
         line: &str,
         size: usize,
-        available_towels: &Vec<String>,
+        available_towels: &[String],
     ) -> Vec<String> {
         let mut result = Vec::new();
         for index in 0..line.len() - size + 1 {
@@ -48,11 +47,11 @@ impl Day19 {
             true
         } else {
             for p in possible_combinations {
-                if target.starts_with(p) {
-                    if self.can_build(&target[p.len()..], possible_combinations) {
-                        self.data_bool.insert(target.to_string(), true);
-                        return true;
-                    }
+                if target.starts_with(p)
+                    && self.can_build(&target[p.len()..], possible_combinations)
+                {
+                    self.data_bool.insert(target.to_string(), true);
+                    return true;
                 }
             }
             self.data_bool.insert(target.to_string(), false);
@@ -85,12 +84,12 @@ impl BaseDay for Day19 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let mut result = 0;
 
-        let input = self.read_file_into_vec();
+        let input = self.read_file_into_vec()?;
         let available_towels = input
-            .get(0)
+            .first()
             .unwrap()
             .split(", ")
             .map(|t| t.to_string())
@@ -113,12 +112,12 @@ impl BaseDay for Day19 {
         Ok(result.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         let mut result = 0;
 
-        let input = self.read_file_into_vec();
+        let input = self.read_file_into_vec()?;
         let available_towels = input
-            .get(0)
+            .first()
             .unwrap()
             .split(", ")
             .map(|t| t.to_string())
@@ -139,8 +138,12 @@ impl BaseDay for Day19 {
         Ok(result.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
 
@@ -158,8 +161,7 @@ mod tests {
     #[test]
     fn part_1_test() {
         init_logger();
-        let mut day = Day19::new();
-        let _path = "./data/day_19/example_1.txt".to_string();
+        let mut day = Day19::new().with_input_path("./data/day_19/example_1.txt");
         let expected = "6";
 
         let result = day.part_1();
@@ -168,8 +170,7 @@ mod tests {
 
     #[test]
     fn part_1_test_2() {
-        let mut day = Day19::new();
-        let _path = "./data/day_19/example_2.txt".to_string();
+        let mut day = Day19::new().with_input_path("./data/day_19/example_2.txt");
         let expected = "1";
 
         let result = day.part_1();
@@ -180,8 +181,7 @@ mod tests {
     fn part_2_test() {
         init_logger();
 
-        let mut day = Day19::new();
-        let _path = "./data/day_19/example_1.txt".to_string();
+        let mut day = Day19::new().with_input_path("./data/day_19/example_1.txt");
         let expected = "16";
 
         let result = day.part_2();
@@ -193,14 +193,14 @@ mod tests {
         let mut day = Day19::new();
         let target = "brabwurr";
         let possible_combinations = HashSet::from_iter(
-            vec!["bwu", "b", "r", "ab", "br", "wu"]
+            ["bwu", "b", "r", "ab", "br", "wu"]
                 .iter()
                 .map(|s| s.to_string())
                 .collect::<Vec<String>>(),
         );
 
         let result = day.can_build(target, &possible_combinations);
-        assert_eq!(result, true);
+        assert!(result);
     }
 
     #[test]
@@ -208,7 +208,7 @@ mod tests {
         let mut day = Day19::new();
         let target = "bbrgwb";
         let possible_combinations = HashSet::from_iter(
-            vec!["r", "b", "g", "br"]
+            ["r", "b", "g", "br"]
                 .iter()
                 .map(|s| s.to_string())
                 .collect::<Vec<String>>(),
@@ -216,6 +216,6 @@ mod tests {
 
         let result = day.can_build(target, &possible_combinations);
         println!("data: {:?}", day.data_bool);
-        assert_eq!(result, false);
+        assert!(!result);
     }
 }
@@ -1,22 +1,21 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
-use std::error::Error;
-use std::path::PathBuf;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 
 pub struct Day2 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Day2 {
     pub fn new() -> Day2 {
         Day2 {
             day_number: 2,
-            file_path: get_input_path(2024, 2),
+            input: get_input_path(2024, 2).into(),
         }
     }
 
-    fn is_level_safe(&self, data: &Vec<u64>) -> bool {
+    fn is_level_safe(&self, data: &[u64]) -> bool {
         let ascending = data[0] < data[1];
         if data[0].abs_diff(data[1]) > 3 || data[0] == data[1] {
             return false;
@@ -46,9 +45,9 @@ impl BaseDay for Day2 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
-        for line in self.read_file_into_vec() {
+        for line in self.read_file_into_vec()? {
             let data = line
                 .split(' ')
                 .map(|c| c.parse().unwrap())
@@ -60,9 +59,9 @@ impl BaseDay for Day2 {
         Ok(result.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
-        for line in self.read_file_into_vec() {
+        for line in self.read_file_into_vec()? {
             let data = line
                 .split(' ')
                 .map(|c| c.parse().unwrap())
@@ -84,7 +83,11 @@ impl BaseDay for Day2 {
         Ok(result.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
@@ -1,11 +1,11 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::graph::{Graph, Node};
 use common::grid::{Grid, Point};
+use common::params::DayParams;
 use common::utils::{DIFFS, bfs_distances};
 use std::collections::{HashMap, HashSet};
-use std::error::Error;
-use std::path::PathBuf;
 
 const NODE_SYMBOL: char = '.';
 const WALL_SYMBOL: char = '#';
@@ -16,7 +16,7 @@ const POSSIBLE_CHARS: [char; 3] = [NODE_SYMBOL, START_SYMBOL, END_SYMBOL];
 
 pub struct Day20 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
     graph: Graph<Point>,
     start_point: u32,
     end_point: u32,
@@ -32,7 +32,7 @@ impl Day20 {
     pub fn new() -> Day20 {
         Day20 {
             day_number: 20,
-            file_path: get_input_path(2024, 20),
+            input: get_input_path(2024, 20).into(),
             graph: Graph::new(),
             start_point: u32::MAX,
             end_point: u32::MAX,
@@ -191,10 +191,10 @@ impl Day20 {
         result
     }
 
-    fn part_2_v2(&mut self) -> u64 {
+    fn part_2_v2(&mut self) -> AocResult<u64> {
         let mut result = 0;
 
-        let input = self.read_file_into_vec_of_vec();
+        let input = self.read_file_into_vec_of_vec()?;
         let grid = Grid::from_vector(&input);
         let rows = grid.rows();
         let cols = grid.cols();
@@ -236,7 +236,7 @@ impl Day20 {
             }
         }
 
-        result
+        Ok(result)
     }
 }
 
@@ -245,7 +245,7 @@ impl BaseDay for Day20 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let mut result = 0;
 
         for wall in self.get_walls() {
@@ -260,63 +260,32 @@ impl BaseDay for Day20 {
         Ok(result.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         let result = self.part_2_v1();
         // let result = self.part_2_v2(&path);
 
         Ok(result.to_string())
     }
 
-    fn setup(&mut self) {
-        let input = self.read_file_into_vec_of_vec();
+    fn setup(&mut self) -> AocResult<()> {
+        let input = self.read_file_into_vec_of_vec()?;
 
         self.build_graph(&input);
         self.grid = Grid::from_vector(&input);
-    }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    fn init_logger() {
-        let _ = env_logger::builder()
-            .filter_level(log::LevelFilter::Debug)
-            .is_test(true)
-            .try_init();
+    fn apply_params(&mut self, params: &DayParams) -> AocResult<()> {
+        self.min_saved_cost = params.get_or("min_saved_cost", self.min_saved_cost)?;
+        Ok(())
     }
 
-    #[test]
-    fn part_1_test() {
-        init_logger();
-
-        let mut day = Day20::new();
-        day.min_saved_cost = 0;
-        let _path = "./data/day_20/example_1.txt".to_string();
-        let expected = "44";
-
-        day.setup();
-        let result = day.part_1().unwrap();
-
-        assert_eq!(result, expected);
+    fn input(&self) -> &Input {
+        &self.input
     }
 
-    #[test]
-    fn part_2_test() {
-        init_logger();
-
-        let mut day = Day20::new();
-        day.min_saved_cost = 50;
-        let path = "./data/day_20/example_1.txt".to_string();
-        let expected = "285";
-
-        day.setup();
-        let result = day.part_2().unwrap();
-
-        assert_eq!(result, expected);
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
@@ -1,20 +1,19 @@
 use common::base_day::BaseDay;
-use std::error::Error;
-use std::path::PathBuf;
+use common::error::AocResult;
 
-use common::file::get_input_path;
+use common::file::{Input, get_input_path};
 use regex::Regex;
 
 pub struct Day3 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Day3 {
     pub fn new() -> Day3 {
         Day3 {
             day_number: 3,
-            file_path: get_input_path(2024, 3),
+            input: get_input_path(2024, 3).into(),
         }
     }
 }
@@ -24,11 +23,11 @@ impl BaseDay for Day3 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
 
-        for data in self.read_file_into_vec() {
-            let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+        let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
+        for data in self.read_file_into_vec()? {
             for (_, [a, b]) in re.captures_iter(data.as_str()).map(|c| c.extract()) {
                 result += a.parse::<u64>().unwrap() * b.parse::<u64>().unwrap();
             }
@@ -36,12 +35,12 @@ impl BaseDay for Day3 {
         Ok(result.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
         let mut enabled = true;
 
-        for data in self.read_file_into_vec() {
-            let re = Regex::new(r"(mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\))").unwrap();
+        let re = Regex::new(r"(mul\((\d{1,3}),(\d{1,3})\)|do\(\)|don't\(\))").unwrap();
+        for data in self.read_file_into_vec()? {
             for match_result in re.find_iter(data.as_str()) {
                 if match_result.as_str() == "do()" {
                     enabled = true;
@@ -52,7 +51,7 @@ impl BaseDay for Day3 {
                         let numbers: Vec<u64> = match_result
                             .as_str()
                             .split('(')
-                            .last()
+                            .next_back()
                             .unwrap()
                             .split(')')
                             .next()
@@ -68,7 +67,11 @@ impl BaseDay for Day3 {
         Ok(result.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
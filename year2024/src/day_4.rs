@@ -1,19 +1,18 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::grid::{Grid, Point};
-use std::error::Error;
-use std::path::PathBuf;
 
 pub struct Day4 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Day4 {
     pub fn new() -> Day4 {
         Day4 {
             day_number: 4,
-            file_path: get_input_path(2024, 4),
+            input: get_input_path(2024, 4).into(),
         }
     }
 
@@ -204,16 +203,14 @@ impl Day4 {
                 y: point.y + 1,
             })
             .is_some_and(|c| *c == 'M')
-        {
-            if grid
+            && grid
                 .get(Point {
                     x: point.x + 1,
                     y: point.y - 1,
                 })
                 .is_some_and(|c| *c == 'S')
-            {
-                return true;
-            }
+        {
+            return true;
         }
         if grid
             .get(Point {
@@ -221,16 +218,14 @@ impl Day4 {
                 y: point.y + 1,
             })
             .is_some_and(|c| *c == 'S')
-        {
-            if grid
+            && grid
                 .get(Point {
                     x: point.x + 1,
                     y: point.y - 1,
                 })
                 .is_some_and(|c| *c == 'M')
-            {
-                return true;
-            }
+        {
+            return true;
         }
 
         false
@@ -247,16 +242,14 @@ impl Day4 {
                 y: point.y - 1,
             })
             .is_some_and(|c| *c == 'M')
-        {
-            if grid
+            && grid
                 .get(Point {
                     x: point.x + 1,
                     y: point.y + 1,
                 })
                 .is_some_and(|c| *c == 'S')
-            {
-                return self.is_second_diagonal_x_mask(grid, point);
-            }
+        {
+            return self.is_second_diagonal_x_mask(grid, point);
         }
 
         if grid
@@ -265,16 +258,14 @@ impl Day4 {
                 y: point.y - 1,
             })
             .is_some_and(|c| *c == 'S')
-        {
-            if grid
+            && grid
                 .get(Point {
                     x: point.x + 1,
                     y: point.y + 1,
                 })
                 .is_some_and(|c| *c == 'M')
-            {
-                return self.is_second_diagonal_x_mask(grid, point);
-            }
+        {
+            return self.is_second_diagonal_x_mask(grid, point);
         }
 
         false
@@ -286,13 +277,13 @@ impl BaseDay for Day4 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
-        let input = self.read_file_into_vec_of_vec();
+        let input = self.read_file_into_vec_of_vec()?;
         let grid = Grid::from_vector(&input);
 
-        for row in 0..input.len() {
-            for col in 0..input[row].len() {
+        for (row, row_data) in input.iter().enumerate() {
+            for col in 0..row_data.len() {
                 let point = Point {
                     x: row as i32,
                     y: col as i32,
@@ -329,21 +320,22 @@ impl BaseDay for Day4 {
         Ok(result.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
-        let input = self.read_file_into_vec_of_vec();
+        let input = self.read_file_into_vec_of_vec()?;
         let grid = Grid::from_vector(&input);
 
-        for row in 0..input.len() {
-            for col in 0..input[row].len() {
+        for (row, row_data) in input.iter().enumerate() {
+            for col in 0..row_data.len() {
                 let point = Point {
                     x: row as i32,
                     y: col as i32,
                 };
-                if grid.get(point).is_some() && *grid.get(point).unwrap() == 'A' {
-                    if self.is_x_mas(&grid, point) {
-                        result += 1;
-                    }
+                if grid.get(point).is_some()
+                    && *grid.get(point).unwrap() == 'A'
+                    && self.is_x_mas(&grid, point)
+                {
+                    result += 1;
                 }
             }
         }
@@ -351,7 +343,11 @@ impl BaseDay for Day4 {
         Ok(result.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
@@ -1,12 +1,13 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use std::collections::HashSet;
-use std::error::Error;
-use std::path::PathBuf;
+
+type RuleError = ((usize, u64), (usize, u64));
 
 pub struct Day5 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
     rules: HashSet<(u64, u64)>,
     reversed_rules: HashSet<(u64, u64)>,
 }
@@ -15,13 +16,13 @@ impl Day5 {
     pub fn new() -> Day5 {
         Day5 {
             day_number: 5,
-            file_path: get_input_path(2024, 5),
+            input: get_input_path(2024, 5).into(),
             rules: HashSet::new(),
             reversed_rules: HashSet::new(),
         }
     }
 
-    fn is_line_valid(&self, data: &Vec<u64>) -> bool {
+    fn is_line_valid(&self, data: &[u64]) -> bool {
         for index in 0..data.len() {
             for second_index in index..data.len() {
                 let first = data.get(index).unwrap();
@@ -34,8 +35,8 @@ impl Day5 {
         true
     }
 
-    fn get_errors(&self, data: &Vec<u64>) -> Vec<((usize, u64), (usize, u64))> {
-        let mut errors: Vec<((usize, u64), (usize, u64))> = Vec::new();
+    fn get_errors(&self, data: &[u64]) -> Vec<RuleError> {
+        let mut errors: Vec<RuleError> = Vec::new();
 
         for index in 0..data.len() {
             for second_index in index..data.len() {
@@ -50,7 +51,7 @@ impl Day5 {
         errors
     }
 
-    fn read_rule(&mut self, line: &String) {
+    fn read_rule(&mut self, line: &str) {
         let (first, second) = line.split_once("|").unwrap();
         self.rules.insert((
             first.parse::<u64>().unwrap(),
@@ -62,14 +63,10 @@ impl Day5 {
         ));
     }
 
-    fn fix_line_and_get_middle(
-        &mut self,
-        data: &Vec<u64>,
-        errors: &Vec<((usize, u64), (usize, u64))>,
-    ) -> u64 {
+    fn fix_line_and_get_middle(&mut self, data: &[u64], errors: &[RuleError]) -> u64 {
         let error = *errors.first().unwrap();
 
-        let mut new_data = data.clone();
+        let mut new_data = data.to_vec();
         new_data.remove(error.0.0);
         new_data.insert(error.0.0, error.1.1);
         new_data.remove(error.1.0);
@@ -87,10 +84,10 @@ impl BaseDay for Day5 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
 
-        for line in self.read_file_into_vec() {
+        for line in self.read_file_into_vec()? {
             if line.contains("|") {
                 self.read_rule(&line);
             } else if line.len() > 1 {
@@ -108,10 +105,10 @@ impl BaseDay for Day5 {
         Ok(result.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
 
-        for line in self.read_file_into_vec() {
+        for line in self.read_file_into_vec()? {
             if line.contains("|") {
                 self.read_rule(&line);
             } else if line.len() > 1 {
@@ -120,7 +117,7 @@ impl BaseDay for Day5 {
                     .map(|x| x.parse::<u64>().unwrap())
                     .collect::<Vec<u64>>();
                 let errors = self.get_errors(&data);
-                if errors.len() > 0 {
+                if !errors.is_empty() {
                     result += self.fix_line_and_get_middle(&data, &errors);
                 }
             }
@@ -129,7 +126,11 @@ impl BaseDay for Day5 {
         Ok(result.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
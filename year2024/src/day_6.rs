@@ -1,19 +1,19 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use common::grid::{Grid, Point};
-use std::error::Error;
-use std::path::PathBuf;
+use common::runner::check_cancelled;
 
 pub struct Day6 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Day6 {
     pub fn new() -> Day6 {
         Day6 {
             day_number: 6,
-            file_path: get_input_path(2024, 6),
+            input: get_input_path(2024, 6).into(),
         }
     }
 
@@ -68,8 +68,8 @@ impl BaseDay for Day6 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
-        let mut grid = Grid::from_vector(&self.read_file_into_vec_of_vec());
+    fn part_1(&mut self) -> AocResult<String> {
+        let mut grid = Grid::from_vector(&self.read_file_into_vec_of_vec()?);
         let mut start_position = *grid.find('^').unwrap().0;
         let mut tmp = self.move_guard(&mut grid, start_position, '^');
         while tmp.is_some() {
@@ -83,8 +83,8 @@ impl BaseDay for Day6 {
     }
 
     #[allow(dead_code)]
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
-        let mut tmp_grid = Grid::from_vector(&self.read_file_into_vec_of_vec());
+    fn part_2(&mut self) -> AocResult<String> {
+        let mut tmp_grid = Grid::from_vector(&self.read_file_into_vec_of_vec()?);
         let mut start_position = *tmp_grid.find('^').unwrap().0;
         let mut tmp = self.move_guard(&mut tmp_grid, start_position, '^');
         while tmp.is_some() {
@@ -98,10 +98,11 @@ impl BaseDay for Day6 {
 
         let mut result = 0;
 
-        let mut grid = Grid::from_vector(&self.read_file_into_vec_of_vec());
+        let mut grid = Grid::from_vector(&self.read_file_into_vec_of_vec()?);
         let start_position = *grid.find('^').unwrap().0;
 
         for point in indexes {
+            check_cancelled()?;
             let mut position_slow = start_position;
             let mut position_fast = start_position;
             if point == start_position {
@@ -132,13 +133,17 @@ impl BaseDay for Day6 {
                 }
             }
 
-            grid = Grid::from_vector(&self.read_file_into_vec_of_vec());
+            grid = Grid::from_vector(&self.read_file_into_vec_of_vec()?);
         }
 
         Ok(result.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
@@ -1,19 +1,18 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 use itertools::Itertools;
-use std::error::Error;
-use std::path::PathBuf;
 
 pub struct Day7 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
 }
 
 impl Day7 {
     pub fn new() -> Day7 {
         Day7 {
             day_number: 7,
-            file_path: get_input_path(2024, 7),
+            input: get_input_path(2024, 7).into(),
         }
     }
 
@@ -31,11 +30,11 @@ impl BaseDay for Day7 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_1(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
         let operators = vec!["+", "*"];
 
-        for line in self.read_file_into_vec() {
+        for line in self.read_file_into_vec()? {
             let split = line.split(":").collect::<Vec<&str>>();
             let expected_result = split[0].parse::<u64>().unwrap();
             let numbers = split[1]
@@ -55,7 +54,7 @@ impl BaseDay for Day7 {
                     } else if op == "*" {
                         return a * b;
                     }
-                    return 0;
+                    0
                 });
 
                 if calibration_value == expected_result {
@@ -67,11 +66,11 @@ impl BaseDay for Day7 {
         Ok(result.to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
+    fn part_2(&mut self) -> AocResult<String> {
         let mut result: u64 = 0;
         let operators = vec!["+", "*", "||"];
 
-        for line in self.read_file_into_vec() {
+        for line in self.read_file_into_vec()? {
             let split = line.split(":").collect::<Vec<&str>>();
             let expected_result = split[0].parse::<u64>().unwrap();
             let numbers = split[1]
@@ -93,7 +92,7 @@ impl BaseDay for Day7 {
                     } else if op == "||" {
                         return (a.to_string() + &b.to_string()).parse::<u64>().unwrap();
                     }
-                    return 0;
+                    0
                 });
 
                 if calibration_value == expected_result {
@@ -105,7 +104,11 @@ impl BaseDay for Day7 {
         Ok(result.to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }
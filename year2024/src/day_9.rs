@@ -1,7 +1,6 @@
 use common::base_day::BaseDay;
-use common::file::get_input_path;
-use std::error::Error;
-use std::path::PathBuf;
+use common::error::AocResult;
+use common::file::{Input, get_input_path};
 
 #[derive(Debug, Clone, Copy, Ord, Eq, PartialOrd, PartialEq)]
 struct Block {
@@ -11,7 +10,7 @@ struct Block {
 
 pub struct Day9 {
     day_number: u32,
-    file_path: PathBuf,
+    input: Input,
     disc: Vec<Block>,
 }
 
@@ -19,12 +18,12 @@ impl Day9 {
     pub fn new() -> Day9 {
         Day9 {
             day_number: 9,
-            file_path: get_input_path(2024, 9),
+            input: get_input_path(2024, 9).into(),
             disc: Vec::new(),
         }
     }
 
-    fn read_input_into_disk(&mut self, input: &Vec<u32>) {
+    fn read_input_into_disk(&mut self, input: &[u32]) {
         self.disc = Vec::new();
         let mut current_id = 0;
 
@@ -57,11 +56,11 @@ impl Day9 {
 
     fn move_block(&mut self) -> bool {
         let mut last_index = self.disc.len() - 1;
-        let mut last_block = self.disc.get_mut(last_index).unwrap().clone();
+        let mut last_block = *self.disc.get_mut(last_index).unwrap();
 
         while last_block.id.is_none() {
             last_index -= 1;
-            last_block = self.disc.get_mut(last_index).unwrap().clone();
+            last_block = *self.disc.get_mut(last_index).unwrap();
         }
 
         let mut first_free_block = self.find_first_free_block();
@@ -110,7 +109,7 @@ impl Day9 {
     fn get_first_available_free_space_with_size(&self, size: u32) -> Option<(usize, Block)> {
         for (index, block) in self.disc.iter().enumerate() {
             if block.id.is_none() && block.size >= size {
-                return Some((index, block.clone()));
+                return Some((index, *block));
             }
         }
 
@@ -125,7 +124,9 @@ impl Day9 {
         let last_file = (index, file);
         let free_space = self.get_first_available_free_space_with_size(last_file.1.size);
 
-        if free_space.is_some() && free_space.unwrap().0 < index {
+        if let Some(free_space) = free_space
+            && free_space.0 < index
+        {
             self.disc.remove(last_file.0);
             self.disc.insert(
                 last_file.0,
@@ -135,13 +136,13 @@ impl Day9 {
                 },
             );
 
-            if free_space.unwrap().1.size == last_file.1.size {
-                self.disc.remove(free_space.unwrap().0);
+            if free_space.1.size == last_file.1.size {
+                self.disc.remove(free_space.0);
             } else {
-                let block = self.disc.get_mut(free_space.unwrap().0).unwrap();
-                block.size = free_space.unwrap().1.size - last_file.1.size;
+                let block = self.disc.get_mut(free_space.0).unwrap();
+                block.size = free_space.1.size - last_file.1.size;
             }
-            self.disc.insert(free_space.unwrap().0, last_file.1);
+            self.disc.insert(free_space.0, last_file.1);
 
             return true;
         }
@@ -155,9 +156,9 @@ impl Day9 {
         // let disc_as_str = self.disc_to_string();
 
         for value in self.disc.iter() {
-            if value.id.is_some() {
+            if let Some(id) = value.id {
                 for _ in 0..value.size {
-                    result += current_index as u64 * value.id.unwrap() as u64;
+                    result += current_index as u64 * id as u64;
                     current_index += 1;
                 }
             } else {
@@ -176,13 +177,13 @@ impl BaseDay for Day9 {
         self.day_number
     }
 
-    fn part_1(&mut self) -> Result<String, Box<dyn Error>> {
-        let data = self.read_file_into_vec_of_vec();
+    fn part_1(&mut self) -> AocResult<String> {
+        let data = self.read_file_into_vec_of_vec()?;
         let input = data
-            .get(0)
+            .first()
             .unwrap()
             .iter()
-            .map(|x| x.to_digit(10).unwrap() as u32)
+            .map(|x| x.to_digit(10).unwrap())
             .collect::<Vec<u32>>();
 
         self.read_input_into_disk(&input);
@@ -191,32 +192,34 @@ impl BaseDay for Day9 {
         Ok(self.calculate_checksum().to_string())
     }
 
-    fn part_2(&mut self) -> Result<String, Box<dyn Error>> {
-        let data = self.read_file_into_vec_of_vec();
+    fn part_2(&mut self) -> AocResult<String> {
+        let data = self.read_file_into_vec_of_vec()?;
         let input = data
-            .get(0)
+            .first()
             .unwrap()
             .iter()
-            .map(|x| x.to_digit(10).unwrap() as u32)
+            .map(|x| x.to_digit(10).unwrap())
             .collect::<Vec<u32>>();
 
         self.read_input_into_disk(&input);
         for block in self.disc.clone().iter().rev() {
             let mut index = 0;
             for b in self.disc.iter() {
-                if b.id.is_some() && block.id.is_some() {
-                    if b.id.unwrap() == block.id.unwrap() {
-                        break;
-                    }
+                if b.id.is_some() && block.id.is_some() && b.id.unwrap() == block.id.unwrap() {
+                    break;
                 }
                 index += 1;
             }
-            self.move_whole_file(index, block.clone());
+            self.move_whole_file(index, *block);
         }
         Ok(self.calculate_checksum().to_string())
     }
 
-    fn get_input_file_path(&self) -> PathBuf {
-        self.file_path.clone()
+    fn input(&self) -> &Input {
+        &self.input
+    }
+
+    fn input_mut(&mut self) -> &mut Input {
+        &mut self.input
     }
 }